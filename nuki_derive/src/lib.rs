@@ -0,0 +1,22 @@
+//! Derive macros for [nuki](https://crates.io/crates/nuki).
+//!
+//! Use them through the `derive` feature of `nuki`, don't depend on this crate directly.
+extern crate proc_macro;
+
+mod pool_object;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive the `PoolObjectTypeId` and the base accessors of a pooled object.
+///
+/// The struct must be `#[repr(C)]` with a `PoolObjectBase` as the first field.
+/// The type id can be specified with `#[pool(type_id = N)]`, otherwise it's derived
+/// from a stable hash of the type name.
+#[proc_macro_derive(PoolObject, attributes(pool))]
+pub fn derive_pool_object(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    pool_object::expand(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -0,0 +1,153 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Error, Fields, Index, LitInt, Member, Result, Type,
+};
+
+/// The type id reserved by the pooled arrays.
+const TYPE_ARRAY: u64 = u16::MAX as u64;
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(s) => &s.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "PoolObject can only be derived for structs",
+            ))
+        }
+    };
+
+    if !has_repr_c(input)? {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "PoolObject requires #[repr(C)] to keep the PoolObjectBase at offset 0",
+        ));
+    }
+
+    let first = match fields {
+        Fields::Named(f) => f.named.first(),
+        Fields::Unnamed(f) => f.unnamed.first(),
+        Fields::Unit => None,
+    }
+    .ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "PoolObject requires a PoolObjectBase as the first field",
+        )
+    })?;
+    if !is_pool_object_base(&first.ty) {
+        return Err(Error::new(
+            first.ty.span(),
+            "the first field of a PoolObject must be PoolObjectBase",
+        ));
+    }
+    let member = match &first.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    };
+
+    let type_id = match parse_type_id(input)? {
+        Some(v) => v,
+        None => hash_type_id(&input.ident.to_string()),
+    };
+    let type_id = type_id as usize;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::nuki::object_pool::PoolObjectTypeId for #name #ty_generics #where_clause {
+            fn pool_object_type_id(&self) -> usize {
+                #type_id
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the reference to the `base`.
+            pub fn base(&self) -> &::nuki::object_pool::PoolObjectBase {
+                &self.#member
+            }
+
+            /// Returns the mutable reference to the `base`.
+            pub fn base_mut(&mut self) -> &mut ::nuki::object_pool::PoolObjectBase {
+                &mut self.#member
+            }
+        }
+    })
+}
+
+fn has_repr_c(input: &DeriveInput) -> Result<bool> {
+    let mut found = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                found = true;
+            } else if meta.input.peek(syn::token::Paren) {
+                // Skip the arguments of `align(N)` and `packed(N)`.
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(found)
+}
+
+fn is_pool_object_base(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) => p
+            .path
+            .segments
+            .last()
+            .map(|s| s.ident == "PoolObjectBase" && s.arguments.is_empty())
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn parse_type_id(input: &DeriveInput) -> Result<Option<u64>> {
+    let mut type_id = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("pool")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("type_id") {
+                let lit: LitInt = meta.value()?.parse()?;
+                let v: u64 = lit.base10_parse()?;
+                if v >= TYPE_ARRAY {
+                    return Err(Error::new_spanned(
+                        lit,
+                        format!("type_id must be less than {}", TYPE_ARRAY),
+                    ));
+                }
+                type_id = Some(v);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported pool attribute, expected `type_id`"))
+            }
+        })?;
+    }
+    Ok(type_id)
+}
+
+/// Returns a stable type id in `1..TYPE_ARRAY` from the FNV-1a hash of the name.
+fn hash_type_id(name: &str) -> u64 {
+    let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    1 + hash % (TYPE_ARRAY - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_type_id() {
+        assert_eq!(hash_type_id("ObjectFoo"), hash_type_id("ObjectFoo"));
+        assert_ne!(hash_type_id("ObjectFoo"), hash_type_id("ObjectBar"));
+        for name in &["", "a", "ObjectFoo", "ObjectBar"] {
+            let v = hash_type_id(name);
+            assert!(v > 0 && v < TYPE_ARRAY);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use nuki::object_pool::*;
+
+#[repr(C)]
+#[derive(Debug, Default, PoolObject)]
+#[pool(type_id = 3)]
+struct ObjectFoo {
+    base: PoolObjectBase,
+    val: u64,
+}
+
+#[repr(C, align(8))]
+#[derive(Debug, Default, PoolObject)]
+struct ObjectBar(PoolObjectBase, [u32; 4]);
+
+#[repr(C)]
+#[derive(Debug, Default, PoolObject)]
+#[pool(type_id = 7)]
+struct ObjectGeneric<T: Default> {
+    base: PoolObjectBase,
+    val: T,
+}
+
+#[test]
+fn test_derive_pool_object() {
+    let mut pool = ForwardPool::with_capacity(1024);
+
+    let foo = pool.alloc::<ObjectFoo>(None).unwrap();
+    foo.val = 1;
+    assert_eq!(foo.pool_object_type_id(), 3);
+    assert_eq!(foo.base().pool_object_type(), 3);
+    assert_eq!(
+        foo.base().pool_object_size(),
+        std::mem::size_of::<ObjectFoo>()
+    );
+    foo.base_mut().set_pool_object_type(4);
+    assert_eq!(foo.base().pool_object_type(), 4);
+
+    let bar = pool.alloc::<ObjectBar>(None).unwrap();
+    let type_id = bar.pool_object_type_id();
+    assert!(type_id > 0 && type_id < POOL_OBJECT_TYPE_ARRAY);
+    assert_eq!(bar.base().pool_object_type(), type_id);
+    assert_eq!(ObjectBar::default().pool_object_type_id(), type_id);
+    assert_ne!(ObjectFoo::default().pool_object_type_id(), type_id);
+
+    let generic = pool.alloc::<ObjectGeneric<u16>>(None).unwrap();
+    assert_eq!(generic.base().pool_object_type(), 7);
+
+    let types: Vec<usize> = pool.iter().map(|o| o.pool_object_type()).collect();
+    assert_eq!(types, vec![4, type_id, 7]);
+}
+
+#[test]
+fn test_derive_pool_object_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
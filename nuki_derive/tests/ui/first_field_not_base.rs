@@ -0,0 +1,10 @@
+use nuki::object_pool::*;
+
+#[repr(C)]
+#[derive(Default, PoolObject)]
+struct ObjectFoo {
+    val: u64,
+    base: PoolObjectBase,
+}
+
+fn main() {}
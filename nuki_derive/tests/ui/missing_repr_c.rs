@@ -0,0 +1,9 @@
+use nuki::object_pool::*;
+
+#[derive(Default, PoolObject)]
+struct ObjectFoo {
+    base: PoolObjectBase,
+    val: u64,
+}
+
+fn main() {}
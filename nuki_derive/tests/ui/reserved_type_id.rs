@@ -0,0 +1,11 @@
+use nuki::object_pool::*;
+
+#[repr(C)]
+#[derive(Default, PoolObject)]
+#[pool(type_id = 65535)]
+struct ObjectFoo {
+    base: PoolObjectBase,
+    val: u64,
+}
+
+fn main() {}
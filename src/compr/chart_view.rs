@@ -0,0 +1,340 @@
+use crate::{rect, vec2, Color, Context, FlagsBuilder, LayoutFormat, Rect, Vec2};
+use std::collections::VecDeque;
+
+/// The vertical range of the chart.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum YRange {
+    /// A fixed `(min, max)` range.
+    Fixed(f32, f32),
+    /// Fit the samples of all series, extended with the ratio of `headroom` at both ends.
+    Auto { headroom: f32 },
+}
+
+/// A series of samples with the label and the color.
+#[derive(Debug)]
+pub struct Series {
+    label: String,
+    color: Color,
+    filled: bool,
+    samples: VecDeque<f32>,
+}
+
+impl Series {
+    /// Returns the label of the series.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the color of the series.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns true if the area below the line is filled.
+    pub fn is_filled(&self) -> bool {
+        self.filled
+    }
+
+    /// Returns an iterator over the samples from the oldest.
+    pub fn samples(&self) -> std::collections::vec_deque::Iter<'_, f32> {
+        self.samples.iter()
+    }
+
+    /// Returns the latest sample.
+    pub fn current(&self) -> Option<f32> {
+        self.samples.back().copied()
+    }
+
+    /// Returns the minimum sample, the `NaN` gaps are ignored.
+    pub fn min(&self) -> Option<f32> {
+        self.samples
+            .iter()
+            .copied()
+            .filter(|v| !v.is_nan())
+            .reduce(f32::min)
+    }
+
+    /// Returns the maximum sample, the `NaN` gaps are ignored.
+    pub fn max(&self) -> Option<f32> {
+        self.samples
+            .iter()
+            .copied()
+            .filter(|v| !v.is_nan())
+            .reduce(f32::max)
+    }
+}
+
+/// A chart of the latest samples of multiple series.
+#[derive(Debug)]
+pub struct ChartView {
+    capacity: usize,
+    y_range: YRange,
+    series: Vec<Series>,
+}
+
+impl ChartView {
+    /// Construct a new chart keeping the latest `capacity` samples of each series.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(2),
+            y_range: YRange::Auto { headroom: 0.1 },
+            series: vec![],
+        }
+    }
+
+    /// Returns the number of samples kept of each series.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Appends a series to the chart, returns the index of the series.
+    pub fn add_series<S: Into<String>>(&mut self, label: S, color: Color) -> usize {
+        self.series.push(Series {
+            label: label.into(),
+            color,
+            filled: false,
+            samples: VecDeque::with_capacity(self.capacity),
+        });
+        self.series.len() - 1
+    }
+
+    /// Fill the area below the line of the series at index.
+    pub fn set_filled(&mut self, series: usize, filled: bool) {
+        if let Some(s) = self.series.get_mut(series) {
+            s.filled = filled;
+        }
+    }
+
+    /// Appends a sample to the series at index, the oldest sample is dropped when full.
+    ///
+    /// A `NaN` sample breaks the line.
+    pub fn push_sample(&mut self, series: usize, value: f32) {
+        if let Some(s) = self.series.get_mut(series) {
+            if s.samples.len() >= self.capacity {
+                s.samples.pop_front();
+            }
+            s.samples.push_back(value);
+        }
+    }
+
+    /// Remove all samples of all series.
+    pub fn clear(&mut self) {
+        for s in self.series.iter_mut() {
+            s.samples.clear();
+        }
+    }
+
+    /// Returns the series reference at index in the chart.
+    pub fn get(&self, series: usize) -> Option<&Series> {
+        self.series.get(series)
+    }
+
+    /// Returns an iterator over the series.
+    pub fn iter(&self) -> std::slice::Iter<'_, Series> {
+        self.series.iter()
+    }
+
+    /// Set the vertical range of the chart.
+    pub fn set_y_range(&mut self, y_range: YRange) {
+        self.y_range = y_range;
+    }
+
+    /// Returns the `(min, max)` vertical range of the current samples.
+    pub fn y_range(&self) -> (f32, f32) {
+        match self.y_range {
+            YRange::Fixed(min, max) => (min, max),
+            YRange::Auto { headroom } => {
+                let min = self.series.iter().filter_map(Series::min).reduce(f32::min);
+                let max = self.series.iter().filter_map(Series::max).reduce(f32::max);
+                match (min, max) {
+                    (Some(min), Some(max)) => {
+                        // Keep a flat line in the middle
+                        let span = if max > min { max - min } else { 1.0 };
+                        (min - span * headroom, max + span * headroom)
+                    }
+                    _ => (0.0, 1.0),
+                }
+            }
+        }
+    }
+}
+
+/// A chart presenter.
+#[derive(Debug)]
+pub struct ChartViewPresenter {
+    height: f32,
+    row_height: f32,
+    grid: usize,
+    show_labels: bool,
+}
+
+impl Default for ChartViewPresenter {
+    fn default() -> Self {
+        Self::new(160.0, 32.0)
+    }
+}
+
+impl ChartViewPresenter {
+    /// Construct a new presenter for chart with the chart `height`.
+    pub fn new(height: f32, row_height: f32) -> Self {
+        Self {
+            height,
+            row_height,
+            grid: 4,
+            show_labels: true,
+        }
+    }
+
+    /// Set the number of grid cells in each direction, 0 for no grid.
+    ///
+    /// The grid includes the outline of the chart, so nothing is stroked with 0.
+    pub fn with_grid(mut self, grid: usize) -> Self {
+        self.grid = grid;
+        self
+    }
+
+    /// Show or hide the current/min/max labels of each series.
+    pub fn with_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
+    }
+
+    /// Returns the line segments of the series in `bounds`, split at the `NaN` gaps.
+    ///
+    /// The latest sample is at the right edge, so fewer samples than the capacity
+    /// only occupy the right part of the chart.
+    fn segments(
+        series: &Series,
+        capacity: usize,
+        bounds: Rect,
+        range: (f32, f32),
+    ) -> Vec<Vec<Vec2>> {
+        let step = bounds.w / (capacity - 1) as f32;
+        let (min, max) = range;
+        let span = if max > min { max - min } else { 1.0 };
+        let skip = capacity - series.samples.len();
+        let mut segments = vec![];
+        let mut points = vec![];
+        for (i, v) in series.samples().enumerate() {
+            if v.is_nan() {
+                if !points.is_empty() {
+                    segments.push(std::mem::take(&mut points));
+                }
+                continue;
+            }
+            let t = ((v - min) / span).clamp(0.0, 1.0);
+            points.push(vec2(
+                bounds.x + step * (skip + i) as f32,
+                bounds.y + bounds.h * (1.0 - t),
+            ));
+        }
+        if !points.is_empty() {
+            segments.push(points);
+        }
+        segments
+    }
+
+    fn present_chart(&self, ctx: &mut Context, cv: &ChartView) {
+        ctx.layout_row(LayoutFormat::Dynamic, self.height, &[1.0]);
+        let bounds = ctx.widget_bounds();
+        let mut grid_color = ctx.style().text().color;
+        grid_color.a /= 6;
+        let range = cv.y_range();
+        if let Some(canvas) = ctx.window_get_canvas_mut() {
+            // Faint grid
+            if self.grid > 0 {
+                for i in 0..=self.grid {
+                    let x = bounds.x + bounds.w * i as f32 / self.grid as f32;
+                    let y = bounds.y + bounds.h * i as f32 / self.grid as f32;
+                    canvas.stroke_line(x, bounds.y, x, bounds.y + bounds.h, 1.0, grid_color);
+                    canvas.stroke_line(bounds.x, y, bounds.x + bounds.w, y, 1.0, grid_color);
+                }
+            }
+            let bottom = bounds.y + bounds.h;
+            for s in cv.iter() {
+                for points in Self::segments(s, cv.capacity(), bounds, range) {
+                    if s.is_filled() {
+                        let mut color = s.color();
+                        color.a /= 3;
+                        // Fill each span as a convex quad
+                        for w in points.windows(2) {
+                            let mut quad = [
+                                w[0].x, w[0].y, w[1].x, w[1].y, w[1].x, bottom, w[0].x, bottom,
+                            ];
+                            canvas.fill_polygon(&mut quad, color);
+                        }
+                    }
+                    if points.len() == 1 {
+                        let p = points[0];
+                        canvas.fill_circle(rect(p.x - 1.5, p.y - 1.5, 3.0, 3.0), s.color());
+                    } else {
+                        let mut line: Vec<f32> = points.iter().flat_map(|p| [p.x, p.y]).collect();
+                        canvas.stroke_polyline(&mut line, 2.0, s.color());
+                    }
+                }
+            }
+        }
+        ctx.spacing(1);
+    }
+
+    /// Present the chart and the labels on the `ctx`.
+    pub fn present(self, ctx: &mut Context, cv: &ChartView) {
+        self.present_chart(ctx, cv);
+        if !self.show_labels {
+            return;
+        }
+        let fmt = |v: Option<f32>| v.map(|v| format!("{:.2}", v)).unwrap_or_else(|| "-".into());
+        for s in cv.iter() {
+            ctx.layout_row(
+                LayoutFormat::Dynamic,
+                self.row_height,
+                &[0.4, 0.2, 0.2, 0.2],
+            );
+            let flags = || FlagsBuilder::align().left().middle().into();
+            ctx.label_colored(s.label().into(), flags(), s.color());
+            ctx.label(format!("cur {}", fmt(s.current())).into(), flags());
+            ctx.label(format!("min {}", fmt(s.min())).into(), flags());
+            ctx.label(format!("max {}", fmt(s.max())).into(), flags());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color_rgba;
+
+    #[test]
+    fn test_chart_view() {
+        let mut cv = ChartView::new(4);
+        let cpu = cv.add_series("CPU", color_rgba(255, 0, 0, 255));
+        assert_eq!(cv.y_range(), (0.0, 1.0));
+
+        for v in &[1.0, 2.0, f32::NAN, 4.0, 5.0] {
+            cv.push_sample(cpu, *v);
+        }
+        let s = cv.get(cpu).unwrap();
+        assert_eq!(s.samples().count(), 4);
+        assert_eq!(s.current(), Some(5.0));
+        assert_eq!(s.min(), Some(2.0));
+        assert_eq!(s.max(), Some(5.0));
+        assert_eq!(cv.y_range(), (1.7, 5.3));
+
+        cv.set_y_range(YRange::Fixed(0.0, 10.0));
+        let s = cv.get(cpu).unwrap();
+        let bounds = rect(0.0, 0.0, 30.0, 10.0);
+        let segments = ChartViewPresenter::segments(s, cv.capacity(), bounds, cv.y_range());
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].len(), 1);
+        assert_eq!((segments[0][0].x, segments[0][0].y), (0.0, 8.0));
+        assert_eq!(segments[1].len(), 2);
+        assert_eq!((segments[1][1].x, segments[1][1].y), (30.0, 5.0));
+
+        // Fewer samples only occupy the right part.
+        cv.clear();
+        cv.push_sample(cpu, 20.0);
+        let s = cv.get(cpu).unwrap();
+        let segments = ChartViewPresenter::segments(s, cv.capacity(), bounds, cv.y_range());
+        assert_eq!((segments[0][0].x, segments[0][0].y), (30.0, 0.0));
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{vec2, Context, FlagsBuilder, Key, LayoutFormat, String as NkString};
 use chrono::{DateTime, Local};
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::ffi::{OsStr, OsString};
-use std::fs::{read_dir, DirEntry};
+use std::fs::{copy, read_dir, remove_file, DirEntry};
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
@@ -38,6 +39,7 @@ pub struct FileList {
     ext_filter: OsString,
     files: Vec<FileInfo>,
     selected: usize,
+    marked: HashSet<PathBuf>,
 }
 
 impl FileList {
@@ -86,6 +88,7 @@ impl FileList {
             ext_filter: ext_filter.as_ref().to_os_string(),
             files,
             selected: 0,
+            marked: HashSet::new(),
         }
     }
 
@@ -155,10 +158,76 @@ impl FileList {
         }
     }
 
+    /// Remove the `selected` file from the disk and the list.
+    pub fn delete_selected(&mut self) -> std::io::Result<()> {
+        if let Some(f) = self.selected_file() {
+            remove_file(&f.path)?;
+            let f = self.files.remove(self.selected);
+            self.marked.remove(&f.path);
+            if self.selected >= self.len() {
+                self.selected = self.len().saturating_sub(1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Toggle the mark of the `selected` file.
+    pub fn toggle_mark_selected(&mut self) {
+        if let Some(f) = self.files.get(self.selected) {
+            if !self.marked.remove(&f.path) {
+                self.marked.insert(f.path.clone());
+            }
+        }
+    }
+
+    /// Returns true if the file at index is marked.
+    pub fn is_marked(&self, index: usize) -> bool {
+        self.get(index)
+            .map(|f| self.marked.contains(&f.path))
+            .unwrap_or(false)
+    }
+
+    /// Returns an iterator over the marked files.
+    pub fn marked_files(&self) -> impl Iterator<Item = &FileInfo> {
+        self.files
+            .iter()
+            .filter(move |f| self.marked.contains(&f.path))
+    }
+
+    /// Unmark all files.
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+
+    /// Copy the marked files into the `dest` directory.
+    ///
+    /// The `progress` is called with the copied bytes and the total bytes before and after
+    /// each file, returns false to abort the copying. Returns the number of copied files.
+    pub fn copy_marked_to<P, F>(&self, dest: P, mut progress: F) -> std::io::Result<usize>
+    where
+        P: AsRef<Path>,
+        F: FnMut(u64, u64) -> bool,
+    {
+        let total: u64 = self.marked_files().map(|f| f.len).sum();
+        let mut copied: u64 = 0;
+        let mut count = 0;
+        for f in self.marked_files() {
+            if !progress(copied, total) {
+                break;
+            }
+            copy(&f.path, dest.as_ref().join(&f.file_name))?;
+            copied += f.len;
+            count += 1;
+        }
+        progress(copied, total);
+        Ok(count)
+    }
+
     /// Clear the files and rescan with constructed `path` and `ext_filter`.
     pub fn refresh(&mut self) {
         self.files = Self::scan_files(&self.path, &self.ext_filter);
         self.selected = 0;
+        self.marked.retain(|p| p.exists());
     }
 }
 
@@ -199,6 +268,7 @@ impl FileListInputCtrl {
 #[derive(Debug)]
 pub struct FileListPresenter {
     row_height: f32,
+    header_height: f32,
 }
 
 impl Default for FileListPresenter {
@@ -210,24 +280,36 @@ impl Default for FileListPresenter {
 impl FileListPresenter {
     /// Construct a new presenter for file list.
     pub fn new(row_height: f32) -> Self {
-        Self { row_height }
+        Self {
+            row_height,
+            header_height: 0.0,
+        }
     }
 
-    fn scroll_to_selected(&self, ctx: &mut Context, fl: &FileList) {
-        let mut y: i32 = 0;
+    /// Set the height of the rows presented above the list in the same window,
+    /// e.g. a tab bar, to keep the selected item visible.
+    pub fn with_header_height(mut self, height: f32) -> Self {
+        self.header_height = height;
+        self
+    }
+
+    /// Returns the vertical scroll offset to keep the selected item visible in a window of
+    /// `win_height`.
+    pub(crate) fn scroll_offset(&self, fl: &FileList, win_height: f32) -> u32 {
+        let mut y: i32 = self.header_height as i32;
         for (i, _f) in fl.iter().enumerate() {
             y += self.row_height as i32;
             if fl.selected == i {
                 break;
             }
         }
+        let offset = y - win_height as i32 + (self.row_height * 2.0) as i32;
+        offset.max(0) as u32
+    }
+
+    fn scroll_to_selected(&self, ctx: &mut Context, fl: &FileList) {
         let win_size = ctx.window_get_size();
-        let offset = y - win_size.y as i32 + (self.row_height * 2.0) as i32;
-        if offset > 0 {
-            ctx.window_set_scroll(0, offset as u32);
-        } else {
-            ctx.window_set_scroll(0, 0);
-        }
+        ctx.window_set_scroll(0, self.scroll_offset(fl, win_size.y));
     }
 
     /// Present each file item on the `ctx`.
@@ -244,6 +326,11 @@ impl FileListPresenter {
         let selected_fg_color = ctx.style().text().color.inverted();
         // Render each file item
         for (i, f) in fl.iter().enumerate() {
+            let mark = if fl.marked.contains(&f.path) {
+                "*"
+            } else {
+                " "
+            };
             if fl.selected == i {
                 ctx.layout_row_colored(
                     LayoutFormat::Dynamic,
@@ -252,7 +339,7 @@ impl FileListPresenter {
                     selected_bg_color,
                 );
                 ctx.label_colored(
-                    format!("{:-4}", i).into(),
+                    format!("{}{:-4}", mark, i).into(),
                     FlagsBuilder::align().left().middle().into(),
                     selected_fg_color,
                 );
@@ -273,7 +360,7 @@ impl FileListPresenter {
             } else {
                 ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &[0.2, 0.4, 0.4]);
                 ctx.label(
-                    format!("{:-4}", i).into(),
+                    format!("{}{:-4}", mark, i).into(),
                     FlagsBuilder::align().left().middle().into(),
                 );
                 ctx.label(
@@ -305,4 +392,69 @@ mod tests {
         let fb = FileList::new("./src", "rs");
         println!("{:#?}", fb);
     }
+
+    #[test]
+    fn test_file_list_delete_selected() {
+        let dir = std::env::temp_dir().join(format!("nuki-file-list-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "a").unwrap();
+        std::fs::write(dir.join("b.txt"), "b").unwrap();
+
+        let mut fl = FileList::new(&dir, "txt");
+        assert_eq!(fl.len(), 2);
+        fl.select_next();
+        let path = fl.selected_file().unwrap().path.clone();
+        fl.delete_selected().unwrap();
+        assert!(!path.exists());
+        assert_eq!(fl.len(), 1);
+        assert_eq!(fl.selected(), 0);
+        fl.delete_selected().unwrap();
+        assert!(fl.is_empty());
+        assert!(fl.delete_selected().is_ok());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_list_copy_marked_to() {
+        let dir = std::env::temp_dir().join(format!("nuki-file-list-copy-{}", std::process::id()));
+        let dest = dir.join("dest");
+        std::fs::create_dir_all(&dest).unwrap();
+        std::fs::write(dir.join("a.txt"), "aa").unwrap();
+        std::fs::write(dir.join("b.txt"), "bbb").unwrap();
+        std::fs::write(dir.join("c.txt"), "c").unwrap();
+
+        let mut fl = FileList::new(&dir, "txt");
+        fl.toggle_mark_selected();
+        fl.select_next();
+        fl.toggle_mark_selected();
+        fl.toggle_mark_selected();
+        fl.select_next();
+        fl.toggle_mark_selected();
+        assert!(fl.is_marked(0) && !fl.is_marked(1) && fl.is_marked(2));
+        let total: u64 = fl.marked_files().map(|f| f.len).sum();
+
+        let mut calls = vec![];
+        let count = fl
+            .copy_marked_to(&dest, |copied, total| {
+                calls.push((copied, total));
+                true
+            })
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(calls.len(), 3);
+        assert_eq!(calls.last(), Some(&(total, total)));
+        for f in fl.marked_files() {
+            assert!(dest.join(&f.file_name).exists());
+        }
+
+        // Abort before copying the first file.
+        let count = fl.copy_marked_to(&dest, |_, _| false).unwrap();
+        assert_eq!(count, 0);
+
+        fl.clear_marks();
+        assert_eq!(fl.marked_files().count(), 0);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,119 @@
+/// The selection of a grid of cells with wrapping arrow-key navigation.
+///
+/// The rows may have different lengths, the relative column is kept when moving
+/// between them. The lengths are passed to each call so the grid may change at any time.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct GridNav {
+    row: usize,
+    col: usize,
+}
+
+impl GridNav {
+    /// Construct a new navigation selecting the top left cell.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the `selected` cell as `(row, col)`.
+    pub fn selected(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Mark the cell at `(row, col)` as `selected`, clamped to the grid of `row_lens`.
+    pub fn select(&mut self, row: usize, col: usize, row_lens: &[usize]) {
+        self.row = row;
+        self.col = col;
+        self.clamp(row_lens);
+    }
+
+    /// Keep the selection in the grid of `row_lens`.
+    pub fn clamp(&mut self, row_lens: &[usize]) {
+        self.row = self.row.min(row_lens.len().saturating_sub(1));
+        let len = row_lens.get(self.row).copied().unwrap_or(0);
+        self.col = self.col.min(len.saturating_sub(1));
+    }
+
+    // Keep the relative column when moving between rows with different lengths.
+    fn move_to_row(&mut self, row: usize, row_lens: &[usize]) {
+        let (old_len, new_len) = (row_lens[self.row], row_lens[row]);
+        self.col = self.col * new_len / old_len.max(1);
+        self.row = row;
+        self.clamp(row_lens);
+    }
+
+    /// Move the selection up, wrap to the bottom row.
+    pub fn select_up(&mut self, row_lens: &[usize]) {
+        if row_lens.is_empty() {
+            return;
+        }
+        self.clamp(row_lens);
+        let row = if self.row == 0 {
+            row_lens.len() - 1
+        } else {
+            self.row - 1
+        };
+        self.move_to_row(row, row_lens);
+    }
+
+    /// Move the selection down, wrap to the top row.
+    pub fn select_down(&mut self, row_lens: &[usize]) {
+        if row_lens.is_empty() {
+            return;
+        }
+        self.clamp(row_lens);
+        let row = if self.row + 1 >= row_lens.len() {
+            0
+        } else {
+            self.row + 1
+        };
+        self.move_to_row(row, row_lens);
+    }
+
+    /// Move the selection left, wrap to the end of the row.
+    pub fn select_left(&mut self, row_lens: &[usize]) {
+        self.clamp(row_lens);
+        if self.col == 0 {
+            self.col = row_lens
+                .get(self.row)
+                .copied()
+                .unwrap_or(0)
+                .saturating_sub(1);
+        } else {
+            self.col -= 1;
+        }
+    }
+
+    /// Move the selection right, wrap to the start of the row.
+    pub fn select_right(&mut self, row_lens: &[usize]) {
+        self.clamp(row_lens);
+        self.col += 1;
+        if self.col >= row_lens.get(self.row).copied().unwrap_or(0) {
+            self.col = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_nav() {
+        let lens = [3, 3, 4];
+        let mut nav = GridNav::new();
+        nav.select_left(&lens);
+        assert_eq!(nav.selected(), (0, 2));
+        nav.select_right(&lens);
+        assert_eq!(nav.selected(), (0, 0));
+        nav.select_up(&lens);
+        assert_eq!(nav.selected(), (2, 0));
+        nav.select_left(&lens);
+        assert_eq!(nav.selected(), (2, 3));
+        nav.select_down(&lens);
+        assert_eq!(nav.selected(), (0, 2));
+        nav.select(5, 5, &lens);
+        assert_eq!(nav.selected(), (2, 3));
+        nav.clamp(&[2]);
+        assert_eq!(nav.selected(), (0, 1));
+    }
+}
@@ -0,0 +1,495 @@
+use crate::{vec2, Context, Key};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+type Predicate<T> = Box<dyn Fn(&T) -> bool>;
+type Compare<T> = Box<dyn Fn(&T, &T) -> Ordering>;
+
+/// The state of a row passed to the row renderer.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RowState {
+    /// The index of the item in the list.
+    pub index: usize,
+    /// Whether the row is `selected`.
+    pub selected: bool,
+    /// The height of the row.
+    pub row_height: f32,
+}
+
+/// The navigation actions of a list view.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ListAction {
+    /// Select the previous selectable item.
+    Prev,
+    /// Select the next selectable item.
+    Next,
+    /// Move the selection up by a page of selectable items.
+    PageUp,
+    /// Move the selection down by a page of selectable items.
+    PageDown,
+    /// Select the first selectable item.
+    Home,
+    /// Select the last selectable item.
+    End,
+    /// Activate the selected item.
+    Activate,
+}
+
+/// A selectable and scrolled list of arbitrary items.
+///
+/// The filter and sort hooks only reorder the visible indices, the items are kept
+/// in their original order. The selection is tracked by the item index.
+pub struct ListView<T> {
+    items: Vec<T>,
+    order: Vec<usize>,
+    selected: Option<usize>,
+    page_size: usize,
+    filter: Option<Predicate<T>>,
+    sort: Option<Compare<T>>,
+    selectable: Option<Predicate<T>>,
+}
+
+impl<T: Debug> Debug for ListView<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListView")
+            .field("items", &self.items)
+            .field("order", &self.order)
+            .field("selected", &self.selected)
+            .field("page_size", &self.page_size)
+            .finish()
+    }
+}
+
+impl<T> Default for ListView<T> {
+    fn default() -> Self {
+        Self::with_items(vec![])
+    }
+}
+
+impl<T> ListView<T> {
+    /// Construct a new empty list view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new list view with `items`.
+    pub fn with_items(items: Vec<T>) -> Self {
+        let mut lv = Self {
+            items,
+            order: vec![],
+            selected: None,
+            page_size: 10,
+            filter: None,
+            sort: None,
+            selectable: None,
+        };
+        lv.refresh();
+        lv
+    }
+
+    /// Recompute the visible indices, must be called after the items changed
+    /// through [`ListView::get_mut`].
+    pub fn refresh(&mut self) {
+        let mut order: Vec<usize> = match &self.filter {
+            Some(f) => (0..self.items.len())
+                .filter(|&i| f(&self.items[i]))
+                .collect(),
+            None => (0..self.items.len()).collect(),
+        };
+        if let Some(f) = &self.sort {
+            order.sort_by(|&a, &b| f(&self.items[a], &self.items[b]));
+        }
+        self.order = order;
+        self.fix_selected();
+    }
+
+    // Move the selection to the first selectable row if the selected one is gone.
+    fn fix_selected(&mut self) {
+        let valid = self
+            .position()
+            .is_some_and(|pos| self.is_selectable(self.order[pos]));
+        if !valid {
+            self.selected = self.find_selectable(0..self.order.len());
+        }
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        match &self.selectable {
+            Some(f) => f(&self.items[index]),
+            None => true,
+        }
+    }
+
+    // Returns the first selectable item index at the positions.
+    fn find_selectable<I: Iterator<Item = usize>>(&self, mut positions: I) -> Option<usize> {
+        positions
+            .find(|&pos| self.is_selectable(self.order[pos]))
+            .map(|pos| self.order[pos])
+    }
+
+    /// Set the filter hiding the items that it returns false.
+    pub fn set_filter<F: Fn(&T) -> bool + 'static>(&mut self, f: F) {
+        self.filter = Some(Box::new(f));
+        self.refresh();
+    }
+
+    /// Remove the filter.
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        self.refresh();
+    }
+
+    /// Set the comparator sorting the visible items, the sort is stable.
+    pub fn set_sort<F: Fn(&T, &T) -> Ordering + 'static>(&mut self, f: F) {
+        self.sort = Some(Box::new(f));
+        self.refresh();
+    }
+
+    /// Remove the comparator, the visible items are in original order.
+    pub fn clear_sort(&mut self) {
+        self.sort = None;
+        self.refresh();
+    }
+
+    /// Set the predicate of the items can be selected, others are skipped by navigation.
+    pub fn set_selectable<F: Fn(&T) -> bool + 'static>(&mut self, f: F) {
+        self.selectable = Some(Box::new(f));
+        self.fix_selected();
+    }
+
+    /// Set the number of rows moved by the page actions.
+    pub fn set_page_size(&mut self, page_size: usize) {
+        self.page_size = page_size.max(1);
+    }
+
+    /// Appends an item to the back of the list.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+        self.refresh();
+    }
+
+    /// Replace all items of the list.
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.selected = None;
+        self.refresh();
+    }
+
+    /// Returns true if the list no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the number of items in the list.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the item reference at index in the list.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Returns the mutable item reference at index in the list.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.items.get_mut(index)
+    }
+
+    /// Returns all items in original order.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the indices of the visible items in display order.
+    pub fn visible(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns an iterator over the visible items with their indices, in display order.
+    pub fn iter_visible(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.order.iter().map(move |&i| (i, &self.items[i]))
+    }
+
+    /// Returns the `selected` item index.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns the `selected` item.
+    pub fn selected_item(&self) -> Option<&T> {
+        self.selected.and_then(|i| self.items.get(i))
+    }
+
+    /// Returns the display position of the `selected` item.
+    pub fn position(&self) -> Option<usize> {
+        self.selected
+            .and_then(|i| self.order.iter().position(|&o| o == i))
+    }
+
+    /// Mark the item at index as `selected`, returns false if hidden or not selectable.
+    pub fn select(&mut self, index: usize) -> bool {
+        if self.order.contains(&index) && self.is_selectable(index) {
+            self.selected = Some(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Mark `prev` selectable item as `selected`.
+    pub fn select_prev(&mut self) {
+        if let Some(pos) = self.position() {
+            if let Some(i) = self.find_selectable((0..pos).rev()) {
+                self.selected = Some(i);
+            }
+        }
+    }
+
+    /// Mark `prev` selectable item as `selected`, wrap to `last` item when current at `first` item.
+    pub fn select_prev_wrapped(&mut self) {
+        let old = self.selected;
+        self.select_prev();
+        if self.selected == old {
+            self.select_last();
+        }
+    }
+
+    /// Mark next selectable item as `selected`.
+    pub fn select_next(&mut self) {
+        if let Some(pos) = self.position() {
+            if let Some(i) = self.find_selectable(pos + 1..self.order.len()) {
+                self.selected = Some(i);
+            }
+        }
+    }
+
+    /// Mark next selectable item as `selected`, wrap to `first` item when current at `last` item.
+    pub fn select_next_wrapped(&mut self) {
+        let old = self.selected;
+        self.select_next();
+        if self.selected == old {
+            self.select_first();
+        }
+    }
+
+    /// Move the selection up by a page of selectable items.
+    pub fn select_page_up(&mut self) {
+        for _ in 0..self.page_size {
+            self.select_prev();
+        }
+    }
+
+    /// Move the selection down by a page of selectable items.
+    pub fn select_page_down(&mut self) {
+        for _ in 0..self.page_size {
+            self.select_next();
+        }
+    }
+
+    /// Mark `first` selectable item as `selected`.
+    pub fn select_first(&mut self) {
+        if let Some(i) = self.find_selectable(0..self.order.len()) {
+            self.selected = Some(i);
+        }
+    }
+
+    /// Mark `last` selectable item as `selected`.
+    pub fn select_last(&mut self) {
+        if let Some(i) = self.find_selectable((0..self.order.len()).rev()) {
+            self.selected = Some(i);
+        }
+    }
+
+    /// Perform the `action`, returns the `selected` item index if activated.
+    ///
+    /// The prev and next actions wrap if `wrapped`.
+    pub fn perform(&mut self, action: ListAction, wrapped: bool) -> Option<usize> {
+        match action {
+            ListAction::Prev if wrapped => self.select_prev_wrapped(),
+            ListAction::Prev => self.select_prev(),
+            ListAction::Next if wrapped => self.select_next_wrapped(),
+            ListAction::Next => self.select_next(),
+            ListAction::PageUp => self.select_page_up(),
+            ListAction::PageDown => self.select_page_down(),
+            ListAction::Home => self.select_first(),
+            ListAction::End => self.select_last(),
+            ListAction::Activate => return self.selected,
+        }
+        None
+    }
+}
+
+/// A list view input controller.
+#[derive(Debug)]
+pub struct ListViewInputCtrl {
+    bindings: Vec<(Key, ListAction)>,
+    wrapped: bool,
+}
+
+impl Default for ListViewInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ListViewInputCtrl {
+    /// Construct a new input controller with the default bindings, wrapping at both ends.
+    ///
+    /// * `Key::Up` / `Key::Down` - Prev / Next
+    /// * `Key::ScrollUp` / `Key::ScrollDown` - PageUp / PageDown
+    /// * `Key::ScrollStart` / `Key::ScrollEnd` - Home / End
+    /// * `Key::Enter` - Activate
+    pub fn new() -> Self {
+        Self {
+            bindings: vec![
+                (Key::Up, ListAction::Prev),
+                (Key::Down, ListAction::Next),
+                (Key::ScrollUp, ListAction::PageUp),
+                (Key::ScrollDown, ListAction::PageDown),
+                (Key::ScrollStart, ListAction::Home),
+                (Key::ScrollEnd, ListAction::End),
+                (Key::Enter, ListAction::Activate),
+            ],
+            wrapped: true,
+        }
+    }
+
+    /// Bind the `key` to the `action`, replacing the previous binding of the `key`.
+    pub fn with_binding(mut self, key: Key, action: ListAction) -> Self {
+        self.bindings.retain(|(k, _)| *k != key);
+        self.bindings.push((key, action));
+        self
+    }
+
+    /// Remove the binding of the `key`.
+    pub fn without_binding(mut self, key: Key) -> Self {
+        self.bindings.retain(|(k, _)| *k != key);
+        self
+    }
+
+    /// Set whether the prev and next actions wrap at both ends.
+    pub fn with_wrapped(mut self, wrapped: bool) -> Self {
+        self.wrapped = wrapped;
+        self
+    }
+
+    /// Processing input events, returns the activated item index.
+    pub fn process<T>(self, ctx: &Context, lv: &mut ListView<T>) -> Option<usize> {
+        let input = ctx.input();
+        let mut activated = None;
+        for (key, action) in self.bindings.iter() {
+            if input.is_key_pressed(*key) {
+                activated = activated.or(lv.perform(*action, self.wrapped));
+            }
+        }
+        activated
+    }
+}
+
+/// A list view presenter.
+#[derive(Debug)]
+pub struct ListViewPresenter {
+    row_height: f32,
+}
+
+impl Default for ListViewPresenter {
+    fn default() -> Self {
+        Self::new(32.0)
+    }
+}
+
+impl ListViewPresenter {
+    /// Construct a new presenter for list view.
+    pub fn new(row_height: f32) -> Self {
+        Self { row_height }
+    }
+
+    fn scroll_to_selected<T>(&self, ctx: &mut Context, lv: &ListView<T>) {
+        let pos = lv.position().unwrap_or(0);
+        let y = (pos + 1) as i32 * self.row_height as i32;
+        let win_size = ctx.window_get_size();
+        let offset = y - win_size.y as i32 + (self.row_height * 2.0) as i32;
+        if offset > 0 {
+            ctx.window_set_scroll(0, offset as u32);
+        } else {
+            ctx.window_set_scroll(0, 0);
+        }
+    }
+
+    /// Present each visible item with the `row` renderer on the `ctx`.
+    ///
+    /// The renderer lays out and draws a row of `row_height` for the item.
+    pub fn present<T, F>(self, ctx: &mut Context, lv: &ListView<T>, row: F)
+    where
+        F: Fn(&mut Context, &T, RowState),
+    {
+        // Save current window states
+        let spacing = *ctx.style().window().spacing();
+        let padding = *ctx.style().window().padding();
+        // Remove spacing and padding
+        ctx.style_mut().window_mut().set_spacing(vec2(0.0, 0.0));
+        ctx.style_mut().window_mut().set_padding(vec2(0.0, 0.0));
+        // Scroll to selected item if necessary
+        self.scroll_to_selected(ctx, lv);
+        // Render each visible item
+        for (index, item) in lv.iter_visible() {
+            let state = RowState {
+                index,
+                selected: lv.selected() == Some(index),
+                row_height: self.row_height,
+            };
+            row(ctx, item, state);
+        }
+        // Restore old window states
+        ctx.style_mut().window_mut().set_spacing(spacing);
+        ctx.style_mut().window_mut().set_padding(padding);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_view() {
+        let mut lv = ListView::with_items(vec![5, 3, 8, 1, 9, 2]);
+        assert_eq!(lv.selected(), Some(0));
+
+        // Sort and filter only reorder the indices.
+        lv.set_sort(|a, b| a.cmp(b));
+        assert_eq!(lv.visible(), [3, 5, 1, 0, 2, 4]);
+        assert_eq!(lv.position(), Some(3));
+        lv.set_filter(|v| v % 2 == 1);
+        assert_eq!(lv.visible(), [3, 1, 0, 4]);
+        assert_eq!(lv.items(), [5, 3, 8, 1, 9, 2]);
+
+        // Navigation over the visible and selectable items.
+        lv.set_selectable(|v| *v != 3);
+        lv.perform(ListAction::Prev, false);
+        assert_eq!(lv.selected_item(), Some(&1));
+        lv.perform(ListAction::Prev, true);
+        assert_eq!(lv.selected_item(), Some(&9));
+        lv.perform(ListAction::Next, true);
+        assert_eq!(lv.selected_item(), Some(&1));
+        lv.set_page_size(2);
+        lv.perform(ListAction::PageDown, false);
+        assert_eq!(lv.selected_item(), Some(&9));
+        lv.perform(ListAction::Home, false);
+        assert_eq!(lv.selected_item(), Some(&1));
+        lv.perform(ListAction::End, false);
+        assert_eq!(lv.perform(ListAction::Activate, false), Some(4));
+
+        // The hidden selection moves to the first selectable row.
+        assert!(!lv.select(2));
+        lv.set_filter(|v| *v < 9);
+        assert_eq!(lv.selected_item(), Some(&1));
+        lv.clear_filter();
+        lv.clear_sort();
+        assert_eq!(lv.visible(), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(lv.selected_item(), Some(&1));
+
+        let mut lv: ListView<i32> = ListView::new();
+        lv.perform(ListAction::Next, true);
+        assert_eq!(lv.perform(ListAction::Activate, true), None);
+    }
+}
@@ -0,0 +1,316 @@
+use super::{ListView, ListViewInputCtrl, ListViewPresenter, RowState};
+use crate::{Color, Context, FlagsBuilder, LayoutFormat};
+
+/// A menu entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuEntry {
+    /// The identifier reported when this entry activated.
+    pub id: usize,
+    /// The text displayed on the entry.
+    pub label: String,
+    /// The trailing hint of the entry, such as a shortcut.
+    pub hint: Option<String>,
+    /// Whether the entry can be selected and activated.
+    pub enabled: bool,
+    /// Whether the entry opens a submenu.
+    pub submenu: bool,
+}
+
+impl MenuEntry {
+    /// Construct a new enabled entry.
+    pub fn new<S: Into<String>>(id: usize, label: S) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            hint: None,
+            enabled: true,
+            submenu: false,
+        }
+    }
+
+    /// Set the trailing hint of the entry.
+    pub fn with_hint<S: Into<String>>(mut self, hint: S) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Set the enabled flag of the entry.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Mark the entry as opening a submenu.
+    pub fn with_submenu(mut self) -> Self {
+        self.submenu = true;
+        self
+    }
+}
+
+/// A vertical menu of entries.
+#[derive(Debug)]
+pub struct MenuList {
+    list: ListView<MenuEntry>,
+}
+
+impl Default for MenuList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MenuList {
+    /// Construct a new empty menu list.
+    pub fn new() -> Self {
+        Self::with_entries(vec![])
+    }
+
+    /// Construct a new menu list with `entries`.
+    pub fn with_entries(entries: Vec<MenuEntry>) -> Self {
+        let mut list = ListView::with_items(entries);
+        list.set_selectable(|e: &MenuEntry| e.enabled);
+        Self { list }
+    }
+
+    /// Appends an entry to the back of the list.
+    pub fn append(&mut self, entry: MenuEntry) {
+        self.list.push(entry);
+    }
+
+    /// Appends an enabled entry with `id` and `label` to the back of the list.
+    pub fn entry<S: Into<String>>(&mut self, id: usize, label: S) {
+        self.append(MenuEntry::new(id, label));
+    }
+
+    /// Set the enabled flag of the entry with `id`.
+    pub fn set_enabled(&mut self, id: usize, enabled: bool) {
+        if let Some(i) = self.list.items().iter().position(|e| e.id == id) {
+            self.list.get_mut(i).unwrap().enabled = enabled;
+            self.list.refresh();
+        }
+    }
+
+    /// Returns true if the list no entries.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the number of entries in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns the entry reference at index in the list.
+    pub fn get(&self, index: usize) -> Option<&MenuEntry> {
+        self.list.get(index)
+    }
+
+    /// Returns the entry reference with `id`.
+    pub fn find(&self, id: usize) -> Option<&MenuEntry> {
+        self.iter().find(|e| e.id == id)
+    }
+
+    /// Returns an iterator over the slice.
+    pub fn iter(&self) -> std::slice::Iter<'_, MenuEntry> {
+        self.list.items().iter()
+    }
+
+    /// Returns the underlying list view.
+    pub fn list(&self) -> &ListView<MenuEntry> {
+        &self.list
+    }
+
+    /// Mark the entry with `id` as `selected`, returns false if not found or disabled.
+    pub fn select(&mut self, id: usize) -> bool {
+        match self.iter().position(|e| e.id == id) {
+            Some(i) => self.list.select(i),
+            None => false,
+        }
+    }
+
+    /// Mark `prev` enabled entry as `selected`.
+    pub fn select_prev(&mut self) {
+        self.list.select_prev();
+    }
+
+    /// Mark `prev` enabled entry as `selected`, wrap to `last` entry when current at `first` entry.
+    pub fn select_prev_wrapped(&mut self) {
+        self.list.select_prev_wrapped();
+    }
+
+    /// Mark next enabled entry as `selected`.
+    pub fn select_next(&mut self) {
+        self.list.select_next();
+    }
+
+    /// Mark next enabled entry as `selected`, wrap to `first` entry when current at `last` entry.
+    pub fn select_next_wrapped(&mut self) {
+        self.list.select_next_wrapped();
+    }
+
+    /// Returns the `selected` entry index, 0 if the list is empty or all entries disabled.
+    pub fn selected(&self) -> usize {
+        self.selected_index().unwrap_or(0)
+    }
+
+    /// Returns the `selected` entry index, `None` if the list is empty or all entries disabled.
+    pub fn selected_index(&self) -> Option<usize> {
+        self.list.selected()
+    }
+
+    /// Returns the `selected` entry, `None` if the list is empty or all entries disabled.
+    pub fn selected_entry(&self) -> Option<&MenuEntry> {
+        self.list.selected_item()
+    }
+}
+
+/// A menu list input controller.
+#[derive(Debug)]
+pub struct MenuListInputCtrl;
+
+impl Default for MenuListInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MenuListInputCtrl {
+    /// Construct a new input controller for menu list.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Processing input events, returns the id of the activated entry.
+    pub fn process(self, ctx: &Context, ml: &mut MenuList) -> Option<usize> {
+        ListViewInputCtrl::new()
+            .process(ctx, &mut ml.list)
+            .and_then(|i| ml.get(i))
+            .map(|e| e.id)
+    }
+}
+
+/// A menu list presenter.
+#[derive(Debug)]
+pub struct MenuListPresenter {
+    row_height: f32,
+}
+
+impl Default for MenuListPresenter {
+    fn default() -> Self {
+        Self::new(32.0)
+    }
+}
+
+impl MenuListPresenter {
+    /// Construct a new presenter for menu list.
+    pub fn new(row_height: f32) -> Self {
+        Self { row_height }
+    }
+
+    // Draw a right pointing triangle in the next cell of the row.
+    fn present_submenu_marker(ctx: &mut Context, color: Color) {
+        let bounds = ctx.widget_bounds();
+        let size = (bounds.h / 3.0).min(bounds.w);
+        let x = bounds.x + (bounds.w - size) / 2.0;
+        let y = bounds.y + (bounds.h - size) / 2.0;
+        if let Some(canvas) = ctx.window_get_canvas_mut() {
+            canvas.fill_triangle(x, y, x + size, y + size / 2.0, x, y + size, color);
+        }
+        ctx.spacing(1);
+    }
+
+    /// Present each menu entry on the `ctx`.
+    pub fn present(self, ctx: &mut Context, ml: &MenuList) {
+        let selected_bg_color = ctx.style().window().background().inverted();
+        let selected_fg_color = ctx.style().text().color.inverted();
+        let normal_fg_color = ctx.style().text().color;
+        let mut disabled_fg_color = normal_fg_color;
+        disabled_fg_color.a /= 2;
+        // Render each menu entry
+        let row = |ctx: &mut Context, e: &MenuEntry, state: RowState| {
+            let fg_color = if state.selected {
+                ctx.layout_row_colored(
+                    LayoutFormat::Dynamic,
+                    state.row_height,
+                    &[0.6, 0.3, 0.1],
+                    selected_bg_color,
+                );
+                selected_fg_color
+            } else {
+                ctx.layout_row(LayoutFormat::Dynamic, state.row_height, &[0.6, 0.3, 0.1]);
+                if e.enabled {
+                    normal_fg_color
+                } else {
+                    disabled_fg_color
+                }
+            };
+            ctx.label_colored(
+                e.label.as_str().into(),
+                FlagsBuilder::align().left().middle().into(),
+                fg_color,
+            );
+            ctx.label_colored(
+                e.hint.as_deref().unwrap_or("").into(),
+                FlagsBuilder::align().right().middle().into(),
+                fg_color,
+            );
+            if e.submenu {
+                Self::present_submenu_marker(ctx, fg_color);
+            } else {
+                ctx.spacing(1);
+            }
+        };
+        ListViewPresenter::new(self.row_height).present(ctx, &ml.list, row);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ml: &mut MenuList, f: fn(&mut MenuList), n: usize) -> Vec<usize> {
+        (0..n)
+            .map(|_| {
+                f(ml);
+                ml.selected_entry().unwrap().id
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_menu_list() {
+        let mut ml = MenuList::with_entries(vec![
+            MenuEntry::new(10, "Open").with_enabled(false),
+            MenuEntry::new(11, "Save").with_hint("Ctrl+S"),
+            MenuEntry::new(12, "Export").with_enabled(false),
+            MenuEntry::new(13, "Recent").with_submenu(),
+            MenuEntry::new(14, "Quit"),
+        ]);
+        // The first disabled entry is skipped.
+        assert_eq!(ml.selected_entry().map(|e| e.id), Some(11));
+        assert_eq!(ml.selected_index(), Some(ml.selected()));
+
+        assert_eq!(ids(&mut ml, MenuList::select_next, 3), [13, 14, 14]);
+        assert_eq!(ids(&mut ml, MenuList::select_prev, 3), [13, 11, 11]);
+        assert_eq!(ids(&mut ml, MenuList::select_prev_wrapped, 2), [14, 13]);
+        assert_eq!(ids(&mut ml, MenuList::select_next_wrapped, 2), [14, 11]);
+
+        assert!(!ml.select(12));
+        assert!(ml.select(13));
+        ml.set_enabled(13, false);
+        assert_eq!(ml.selected_entry().map(|e| e.id), Some(11));
+        ml.set_enabled(12, true);
+        assert_eq!(ids(&mut ml, MenuList::select_next, 2), [12, 14]);
+
+        for id in 10..15 {
+            ml.set_enabled(id, false);
+        }
+        assert!(ml.selected_entry().is_none());
+        ml.select_next_wrapped();
+        ml.select_prev_wrapped();
+        assert!(ml.selected_entry().is_none());
+        assert!(MenuList::new().selected_entry().is_none());
+        assert_eq!(MenuList::new().selected(), 0);
+        assert_eq!(MenuList::new().selected_index(), None);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{rect, Context, FlagsBuilder, Key, LayoutFormat, Rect, Vec2};
+
+/// The buttons of a message box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageBoxButtons {
+    /// A single "OK" button.
+    Ok,
+    /// The "OK" and "Cancel" buttons.
+    OkCancel,
+    /// The "Yes" and "No" buttons.
+    YesNo,
+    /// The buttons with custom labels, the first one is the affirmative.
+    Custom(Vec<String>),
+}
+
+impl MessageBoxButtons {
+    fn labels(self) -> Vec<String> {
+        match self {
+            MessageBoxButtons::Ok => vec!["OK".into()],
+            MessageBoxButtons::OkCancel => vec!["OK".into(), "Cancel".into()],
+            MessageBoxButtons::YesNo => vec!["Yes".into(), "No".into()],
+            MessageBoxButtons::Custom(labels) => labels,
+        }
+    }
+}
+
+/// The result of a message box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageBoxResult {
+    /// The button at index was pressed.
+    Button(usize),
+    /// The message box was dismissed without pressing any button.
+    Cancelled,
+}
+
+impl MessageBoxResult {
+    /// Returns true if the first (affirmative) button was pressed.
+    pub fn is_accepted(self) -> bool {
+        self == MessageBoxResult::Button(0)
+    }
+}
+
+/// A message box with a set of buttons.
+///
+/// The message box is just view state, the caller polls the result with
+/// [`MessageBox::poll`] after each frame.
+#[derive(Debug)]
+pub struct MessageBox {
+    title: String,
+    message: String,
+    buttons: Vec<String>,
+    selected: usize,
+    open: bool,
+    result: Option<MessageBoxResult>,
+}
+
+impl MessageBox {
+    /// Construct a new opened message box.
+    ///
+    /// The `title` is also used as the window name, so it should be unique among the windows.
+    pub fn new<T, M>(title: T, message: M, buttons: MessageBoxButtons) -> Self
+    where
+        T: Into<String>,
+        M: Into<String>,
+    {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            buttons: buttons.labels(),
+            selected: 0,
+            open: true,
+            result: None,
+        }
+    }
+
+    /// Returns the title of the message box.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the message of the message box.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Set the message of the message box.
+    pub fn set_message<M: Into<String>>(&mut self, message: M) {
+        self.message = message.into();
+    }
+
+    /// Returns the labels of the buttons.
+    pub fn buttons(&self) -> &[String] {
+        &self.buttons
+    }
+
+    /// Returns the `selected` button index.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Mark `prev` button as `selected`.
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Mark next button as `selected`.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.buttons.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Press the `selected` button and close the message box.
+    pub fn press(&mut self) {
+        if self.open {
+            self.open = false;
+            self.result = Some(if self.buttons.is_empty() {
+                MessageBoxResult::Cancelled
+            } else {
+                MessageBoxResult::Button(self.selected)
+            });
+        }
+    }
+
+    /// Dismiss and close the message box.
+    pub fn cancel(&mut self) {
+        if self.open {
+            self.open = false;
+            self.result = Some(MessageBoxResult::Cancelled);
+        }
+    }
+
+    /// Returns true if the message box is waiting for the user.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Reopen the message box with the first button selected.
+    pub fn reopen(&mut self) {
+        self.selected = 0;
+        self.open = true;
+        self.result = None;
+    }
+
+    /// Returns the result once after the message box closed.
+    pub fn poll(&mut self) -> Option<MessageBoxResult> {
+        self.result.take()
+    }
+}
+
+/// A message box input controller.
+///
+/// Nuklear has no escape key, the `Key::Backspace` is used to dismiss the message box,
+/// the backends can also call [`MessageBox::cancel`] directly.
+#[derive(Debug)]
+pub struct MessageBoxInputCtrl;
+
+impl Default for MessageBoxInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageBoxInputCtrl {
+    /// Construct a new input controller for message box.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Processing input events.
+    pub fn process(self, ctx: &Context, mb: &mut MessageBox) {
+        if !mb.is_open() {
+            return;
+        }
+        let input = ctx.input();
+        if input.is_key_pressed(Key::Left) {
+            mb.select_prev();
+        }
+        if input.is_key_pressed(Key::Right) {
+            mb.select_next();
+        }
+        if input.is_key_pressed(Key::Enter) {
+            mb.press();
+        } else if input.is_key_pressed(Key::Backspace) {
+            mb.cancel();
+        }
+    }
+}
+
+/// A message box presenter.
+#[derive(Debug)]
+pub struct MessageBoxPresenter {
+    size: Vec2,
+    row_height: f32,
+}
+
+impl Default for MessageBoxPresenter {
+    fn default() -> Self {
+        Self::new(Vec2 { x: 400.0, y: 200.0 }, 32.0)
+    }
+}
+
+impl MessageBoxPresenter {
+    /// Construct a new presenter for message box with the window `size`.
+    pub fn new(size: Vec2, row_height: f32) -> Self {
+        Self { size, row_height }
+    }
+
+    /// Present the message box as a window centered in the `area`.
+    ///
+    /// Should be called after the other windows to keep it above them.
+    pub fn present(self, ctx: &mut Context, mb: &MessageBox, area: Rect) {
+        if !mb.is_open() {
+            // Release the window of a closed message box
+            if !ctx.window_is_closed(mb.title().into()) {
+                ctx.window_close(mb.title().into());
+            }
+            return;
+        }
+        let bounds = rect(
+            area.x + (area.w - self.size.x) / 2.0,
+            area.y + (area.h - self.size.y) / 2.0,
+            self.size.x,
+            self.size.y,
+        );
+        if ctx.begin(
+            mb.title().into(),
+            bounds,
+            FlagsBuilder::panel()
+                .border()
+                .title()
+                .no_scroll_bar()
+                .into(),
+        ) {
+            // Fill the remaining space with the message
+            let spacing = ctx.style().window().spacing().y;
+            let height = ctx.window_get_content_region_size().y - self.row_height - spacing;
+            ctx.layout_row(LayoutFormat::Dynamic, height.max(self.row_height), &[1.0]);
+            ctx.label_wrap(mb.message().into());
+            // Render the buttons
+            let n = mb.buttons().len().max(1);
+            let ratios = vec![1.0 / n as f32; n];
+            ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &ratios);
+            let selected_bg_color = ctx.style().window().background().inverted();
+            let selected_fg_color = ctx.style().text().color.inverted();
+            let border_color = ctx.style().text().color;
+            for (i, b) in mb.buttons().iter().enumerate() {
+                let bounds = ctx.widget_bounds();
+                if mb.selected() == i {
+                    if let Some(canvas) = ctx.window_get_canvas_mut() {
+                        canvas.fill_rect(bounds, 0.0, selected_bg_color);
+                    }
+                    ctx.label_colored(
+                        b.as_str().into(),
+                        FlagsBuilder::align().centered().middle().into(),
+                        selected_fg_color,
+                    );
+                } else {
+                    if let Some(canvas) = ctx.window_get_canvas_mut() {
+                        canvas.stroke_rect(bounds, 0.0, 1.0, border_color);
+                    }
+                    ctx.label(
+                        b.as_str().into(),
+                        FlagsBuilder::align().centered().middle().into(),
+                    );
+                }
+            }
+        }
+        ctx.end();
+        // Keep the message box above the caller's windows
+        ctx.window_set_focus(mb.title().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_box() {
+        let mut mb = MessageBox::new("Delete", "Delete 3 files?", MessageBoxButtons::YesNo);
+        assert_eq!(mb.buttons(), ["Yes", "No"]);
+        assert!(mb.is_open());
+        assert_eq!(mb.poll(), None);
+
+        mb.select_next();
+        mb.select_next();
+        assert_eq!(mb.selected(), 1);
+        mb.press();
+        assert!(!mb.is_open());
+        assert_eq!(mb.poll(), Some(MessageBoxResult::Button(1)));
+        assert_eq!(mb.poll(), None);
+
+        // Closed message box ignores further input.
+        mb.cancel();
+        assert_eq!(mb.poll(), None);
+
+        mb.reopen();
+        assert_eq!(mb.selected(), 0);
+        mb.press();
+        assert!(mb.poll().unwrap().is_accepted());
+
+        mb.reopen();
+        mb.cancel();
+        assert_eq!(mb.poll(), Some(MessageBoxResult::Cancelled));
+    }
+}
@@ -1,9 +1,55 @@
 //! Composited Presenters
 //!
 //! The composited presenters, currently supports:
+//! * [`ChartView`] - A chart of the latest samples, Usually used to build monitoring panels.
 //! * [`FileList`] - A list of disk files, Usually used to build file browsers.
+//! * [`ListView`] - A selectable and scrolled list of arbitrary items rendered by a row closure.
+//! * [`MenuList`] - A vertical menu of entries, Usually used to build main or context menus.
+//! * [`MessageBox`] - A modal message with buttons, Usually used to confirm operations.
+//! * [`NumPad`] - A compact 0-9 keypad, Usually used to enter PINs and values with a remote control.
+//! * [`ProgressDialog`] - A modal progress of a long-running operation.
 //! * [`PropertySheet`] - A collection with variant of properties,
 //!   Usually used to build some settings or preferences panels.
+//! * [`StatusBar`] - A one-line bar of the segments, Usually used to show time, device states and hints.
+//! * [`TabbedPanel`] - An ordered list of tabs hosting other composited presenters.
+//! * [`TableView`] - A table of rows with typed and sortable columns.
+//! * [`ToastManager`] - A stack of transient notifications, Usually used to show brief messages.
+//! * [`VirtualKeyboard`] - An on-screen keyboard, Usually used to enter text with a remote control.
+//! * [`Wizard`] - An ordered pages of a multi-step flow, Usually used to build first-boot setups.
+//!
+//! # ChartView
+//!
+//! A chart of the latest samples of multiple series, it's display-only without input controller.
+//!
+//! ```ignore
+//! use nuki::compr::{ChartView, ChartViewPresenter, YRange};
+//!
+//! // Setup
+//! let mut cv = ChartView::new(120);
+//! let sine = cv.add_series("Sine", nuki::color_rgba(0, 192, 255, 255));
+//! let cosine = cv.add_series("Cosine", nuki::color_rgba(255, 128, 0, 255));
+//! cv.set_filled(sine, true);
+//! cv.set_y_range(YRange::Fixed(-1.0, 1.0));
+//! let mut t = 0.0f32;
+//!
+//! // Rendering
+//! t += 0.1;
+//! cv.push_sample(sine, t.sin());
+//! cv.push_sample(cosine, t.cos());
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Hello, ChartView!"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 480f32,
+//!         h: 320f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     ChartViewPresenter::new(160.0, 32.0).present(&mut nk_ctx, &cv);
+//! }
+//! nk_ctx.end();
+//! ```
 //!
 //! # FileList
 //!
@@ -39,6 +85,208 @@
 //! }
 //! ```
 //!
+//! # ListView
+//!
+//! A selectable and scrolled list of arbitrary items, the rows are rendered by a closure.
+//!
+//! ```ignore
+//! use nuki::compr::{ListView, ListViewInputCtrl, ListViewPresenter};
+//!
+//! // Setup
+//! let mut lv = ListView::with_items(vec!["Intro.mp4", "Chapter 1.mp4", "Chapter 2.mp4"]);
+//! lv.set_sort(|a, b| a.cmp(b));
+//!
+//! // Rendering
+//! let mut activated = None;
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Hello, ListView!"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 480f32,
+//!         h: 480f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     activated = ListViewInputCtrl::new().process(&nk_ctx, &mut lv);
+//!     let selected_bg_color = nk_ctx.style().window().background().inverted();
+//!     ListViewPresenter::new(32.0).present(&mut nk_ctx, &lv, |ctx, name, state| {
+//!         if state.selected {
+//!             ctx.layout_row_colored(
+//!                 nuki::LayoutFormat::Dynamic,
+//!                 state.row_height,
+//!                 &[1.0],
+//!                 selected_bg_color,
+//!             );
+//!         } else {
+//!             ctx.layout_row(nuki::LayoutFormat::Dynamic, state.row_height, &[1.0]);
+//!         }
+//!         ctx.label((*name).into(), nuki::FlagsBuilder::align().left().middle().into());
+//!     });
+//! }
+//! nk_ctx.end();
+//!
+//! // Processing activated item.
+//! if let Some(index) = activated {
+//!     println!("Play {}", lv.get(index).unwrap());
+//! }
+//! ```
+//!
+//! # MenuList
+//!
+//! A vertical menu of entries.
+//!
+//! ```ignore
+//! use nuki::compr::{MenuEntry, MenuList, MenuListInputCtrl, MenuListPresenter};
+//!
+//! // Setup
+//! let mut ml = MenuList::new();
+//! ml.entry(0, "Open");
+//! ml.append(MenuEntry::new(1, "Save").with_hint("Ctrl+S"));
+//! ml.append(MenuEntry::new(2, "Export").with_enabled(false));
+//! ml.append(MenuEntry::new(3, "Recent").with_submenu());
+//! ml.entry(4, "Quit");
+//!
+//! // Rendering
+//! let mut activated = None;
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Hello, MenuList!"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 480f32,
+//!         h: 480f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     activated = MenuListInputCtrl::new().process(&nk_ctx, &mut ml);
+//!     MenuListPresenter::new(32.0).present(&mut nk_ctx, &ml);
+//! }
+//! nk_ctx.end();
+//!
+//! // Processing activated entry.
+//! match activated {
+//!     Some(4) => { /* Quit */ }
+//!     Some(_) => { /* Do something if you want */ }
+//!     None => {}
+//! }
+//! ```
+//!
+//! # MessageBox
+//!
+//! A modal message with buttons, here to confirm deleting the selected file of a [`FileList`].
+//!
+//! ```ignore
+//! use nuki::compr::{MessageBox, MessageBoxButtons, MessageBoxInputCtrl, MessageBoxPresenter};
+//!
+//! // Setup
+//! let mut confirm: Option<MessageBox> = None;
+//!
+//! // Rendering
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Hello, FileList!"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 480f32,
+//!         h: 480f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     // Only the message box receives keys while it's opened
+//!     if confirm.is_none() {
+//!         FileListInputCtrl::new().process(&nk_ctx, &mut fl);
+//!         if nk_ctx.input().is_key_pressed(nuki::Key::Del) {
+//!             if let Some(f) = fl.selected_file() {
+//!                 let message = format!("Delete {:?}?", f.file_name);
+//!                 confirm = Some(MessageBox::new("Confirm", message, MessageBoxButtons::YesNo));
+//!             }
+//!         }
+//!     }
+//!     FileListPresenter::new(32.0).present(&mut nk_ctx, &fl);
+//! }
+//! nk_ctx.end();
+//!
+//! if let Some(mb) = confirm.as_mut() {
+//!     MessageBoxInputCtrl::new().process(&nk_ctx, mb);
+//!     MessageBoxPresenter::default().present(&mut nk_ctx, mb, screen_bounds);
+//!     if let Some(result) = mb.poll() {
+//!         if result.is_accepted() {
+//!             fl.delete_selected()?;
+//!         }
+//!         // The window was released by the presenter
+//!         confirm = None;
+//!     }
+//! }
+//! ```
+//!
+//! # NumPad
+//!
+//! A compact 0-9 keypad, here to enter a masked parental-control PIN.
+//!
+//! ```ignore
+//! use nuki::compr::{NumPad, NumPadInputCtrl, NumPadPresenter, NumPadResult};
+//!
+//! // Setup
+//! let mut np = NumPad::new(4).with_masked();
+//!
+//! // Processing
+//! match NumPadInputCtrl::new().process(&nk_ctx, &mut np) {
+//!     Some(NumPadResult::Committed(pin)) => println!("PIN = {}", pin),
+//!     Some(NumPadResult::Cancelled) => println!("Cancelled"),
+//!     None => {}
+//! }
+//!
+//! // Rendering
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Hello, NumPad!"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 240f32,
+//!         h: 240f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     NumPadPresenter::new(32.0).present(&mut nk_ctx, &np);
+//! }
+//! nk_ctx.end();
+//! ```
+//!
+//! # ProgressDialog
+//!
+//! A modal progress of a long-running operation, here to copy the marked files of a [`FileList`]
+//! in a worker thread.
+//!
+//! ```ignore
+//! use nuki::compr::{ProgressDialog, ProgressDialogInputCtrl, ProgressDialogPresenter};
+//! use std::sync::atomic::Ordering;
+//! use std::sync::mpsc;
+//!
+//! // Setup
+//! let mut pd = ProgressDialog::new("Copying").with_cancel();
+//! let cancelled = pd.cancel_flag();
+//! let (tx, rx) = mpsc::channel();
+//! let worker = std::thread::spawn(move || {
+//!     fl.copy_marked_to("/mnt/usb", |copied, total| {
+//!         let _ = tx.send((copied, total));
+//!         !cancelled.load(Ordering::SeqCst)
+//!     })
+//! });
+//!
+//! // Rendering
+//! for (copied, total) in rx.try_iter() {
+//!     pd.set_progress(copied as f32 / total.max(1) as f32);
+//!     pd.set_status(format!("{} of {} bytes", copied, total));
+//! }
+//! if worker.is_finished() {
+//!     // Stop presenting the dialog and check the result of `worker.join()`
+//! } else {
+//!     ProgressDialogInputCtrl::new().process(&nk_ctx, &pd);
+//!     ProgressDialogPresenter::default().present(&mut nk_ctx, &pd, screen_bounds);
+//! }
+//! ```
+//!
 //! # PropertySheet
 //!
 //! A collection with variant of properties.
@@ -79,9 +327,259 @@
 //! }
 //! nk_ctx.end();
 //! ```
+//!
+//! # StatusBar
+//!
+//! A one-line bar of the segments aligned to the left, center or right of the current window.
+//!
+//! ```ignore
+//! use nuki::compr::{StatusAlign, StatusBar, StatusBarPresenter, StatusContent};
+//!
+//! // Setup
+//! let mut sb = StatusBar::new();
+//! sb.push("hint", StatusAlign::Left, StatusContent::text("▲▼ navigate  OK select"));
+//! sb.push("title", StatusAlign::Center, StatusContent::text("Settings"));
+//! sb.push("wifi", StatusAlign::Right, StatusContent::symbol(nuki::SymbolType::CircleSolid, "WiFi"));
+//! sb.push("clock", StatusAlign::Right, StatusContent::clock("%H:%M"));
+//!
+//! // Rendering
+//! sb.set_visible("wifi", wifi_connected);
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Hello, StatusBar!"),
+//!     nuki::Rect {
+//!         x: 0f32,
+//!         y: 0f32,
+//!         w: 1280f32,
+//!         h: 720f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().no_scroll_bar().into(),
+//! ) {
+//!     // Other contents of the window
+//!     StatusBarPresenter::new(32.0).present(&mut nk_ctx, &sb);
+//! }
+//! nk_ctx.end();
+//! ```
+//!
+//! # TabbedPanel
+//!
+//! An ordered list of tabs hosting other composited presenters.
+//!
+//! ```ignore
+//! use nuki::compr::{TabContent, TabbedPanel, TabbedPanelInputCtrl, TabbedPanelPresenter};
+//!
+//! // Setup
+//! let mut tp = TabbedPanel::new();
+//! tp.append("Video", TabContent::PropertySheet(video_sheet));
+//! tp.append("Audio", TabContent::PropertySheet(audio_sheet));
+//! tp.append("Files", TabContent::FileList(FileList::new("/media", "*")));
+//! tp.append(
+//!     "About",
+//!     TabContent::custom(|ctx: &mut nuki::Context| {
+//!         ctx.layout_row(nuki::LayoutFormat::Dynamic, 32.0, &[1.0]);
+//!         ctx.label("nuki".into(), nuki::FlagsBuilder::align().left().middle().into());
+//!     }),
+//! );
+//!
+//! // Rendering
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Settings"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 480f32,
+//!         h: 480f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     // Or switch tabs with the ScrollUp/ScrollDown keys directly
+//!     // TabbedPanelInputCtrl::with_keys(TabSwitchKeys::Keys { prev: Key::ScrollUp, next: Key::ScrollDown })
+//!     TabbedPanelInputCtrl::new().process(&nk_ctx, &mut tp);
+//!     TabbedPanelPresenter::new(32.0).present(&mut nk_ctx, &tp);
+//! }
+//! nk_ctx.end();
+//! ```
+//!
+//! # TableView
+//!
+//! A table of rows with typed and sortable columns.
+//!
+//! ```ignore
+//! use nuki::compr::{Column, TableView, TableViewInputCtrl, TableViewPresenter};
+//! use nuki::TextAlignment;
+//!
+//! struct Channel {
+//!     name: String,
+//!     bitrate: u32,
+//! }
+//!
+//! // Setup
+//! let columns = vec![
+//!     Column::new("Name", 0.7, |c: &Channel| c.name.clone())
+//!         .with_sort_key(|c: &Channel| c.name.clone()),
+//!     Column::new("Bitrate", 0.3, |c: &Channel| format!("{} kbps", c.bitrate))
+//!         .with_align(TextAlignment::Right)
+//!         .with_sort_key(|c: &Channel| c.bitrate),
+//! ];
+//! let mut tv = TableView::new(columns, channels);
+//!
+//! // Rendering
+//! let mut activated = None;
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Hello, TableView!"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 480f32,
+//!         h: 480f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     activated = TableViewInputCtrl::new().process(&nk_ctx, &mut tv);
+//!     TableViewPresenter::new(32.0).present(&mut nk_ctx, &tv);
+//! }
+//! nk_ctx.end();
+//!
+//! // Processing activated row.
+//! if let Some(i) = activated {
+//!     let channel = &tv.rows()[i];
+//! }
+//! ```
+//!
+//! # ToastManager
+//!
+//! A stack of transient notifications faded out without the caller managing timers.
+//!
+//! ```ignore
+//! use nuki::compr::{ToastManager, ToastPresenter, ToastSeverity};
+//! use std::time::{Duration, Instant};
+//!
+//! // Setup
+//! let mut tm = ToastManager::new();
+//! let mut last_frame = Instant::now();
+//!
+//! // Processing
+//! tm.push("Settings saved", ToastSeverity::Success, Duration::from_secs(3));
+//!
+//! // Rendering, after the other windows
+//! let now = Instant::now();
+//! ToastPresenter::default().present(&mut nk_ctx, &mut tm, screen_bounds, now - last_frame);
+//! last_frame = now;
+//! ```
+//!
+//! # VirtualKeyboard
+//!
+//! An on-screen keyboard, the string properties of [`PropertySheet`] are edited with it
+//! when the `keyboard-only` feature enabled.
+//!
+//! ```ignore
+//! use nuki::compr::{VirtualKeyboard, VirtualKeyboardInputCtrl, VirtualKeyboardPresenter, VirtualKeyboardResult};
+//!
+//! // Setup
+//! let mut vk = VirtualKeyboard::with_text("nuki", 32);
+//!
+//! // Rendering
+//! let mut result = None;
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Hello, VirtualKeyboard!"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 480f32,
+//!         h: 240f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     result = VirtualKeyboardInputCtrl::new().process(&nk_ctx, &mut vk);
+//!     VirtualKeyboardPresenter::new(32.0).present(&mut nk_ctx, &vk);
+//! }
+//! nk_ctx.end();
+//!
+//! // Processing the result.
+//! match result {
+//!     Some(VirtualKeyboardResult::Committed(text)) => { /* Use the text */ }
+//!     Some(VirtualKeyboardResult::Cancelled) => { /* Discard */ }
+//!     None => {}
+//! }
+//! ```
+//!
+//! # Wizard
+//!
+//! An ordered pages of a multi-step flow.
+//!
+//! ```ignore
+//! use nuki::compr::{TabContent, Wizard, WizardInputCtrl, WizardPresenter};
+//!
+//! // Setup
+//! let mut wz = Wizard::new();
+//! wz.append("Language", TabContent::PropertySheet(language_sheet));
+//! wz.append("Network", TabContent::PropertySheet(network_sheet))
+//!     .set_validator(|ps| match ps.get_value_string_of("SSID") {
+//!         Some(v) if !v.is_empty() => Ok(()),
+//!         _ => Err("Please enter the SSID".into()),
+//!     });
+//! wz.append("Time Zone", TabContent::PropertySheet(time_zone_sheet));
+//! wz.on_finished(|sheets| {
+//!     // Save the settings of all sheets
+//! });
+//!
+//! // Rendering
+//! if nk_ctx.begin(
+//!     nuki::nk_string!("Setup"),
+//!     nuki::Rect {
+//!         x: 200f32,
+//!         y: 200f32,
+//!         w: 480f32,
+//!         h: 480f32,
+//!     },
+//!     nuki::FlagsBuilder::panel().border().title().into(),
+//! ) {
+//!     WizardInputCtrl::new().process(&nk_ctx, &mut wz);
+//!     WizardPresenter::new(32.0).present(&mut nk_ctx, &wz);
+//! }
+//! nk_ctx.end();
+//! ```
+
+mod chart_view;
+pub use chart_view::*;
 
 mod file_list;
 pub use file_list::*;
 
+mod grid_nav;
+pub use grid_nav::*;
+
+mod list_view;
+pub use list_view::*;
+
+mod menu_list;
+pub use menu_list::*;
+
+mod message_box;
+pub use message_box::*;
+
+mod num_pad;
+pub use num_pad::*;
+
+mod progress_dialog;
+pub use progress_dialog::*;
+
 mod property_sheet;
 pub use property_sheet::*;
+
+mod status_bar;
+pub use status_bar::*;
+
+mod tabbed_panel;
+pub use tabbed_panel::*;
+
+mod table_view;
+pub use table_view::*;
+
+mod toast;
+pub use toast::*;
+
+mod virtual_keyboard;
+pub use virtual_keyboard::*;
+
+mod wizard;
+pub use wizard::*;
@@ -0,0 +1,397 @@
+use super::GridNav;
+use crate::{vec2, Context, FlagsBuilder, Key, LayoutFormat};
+
+/// A key of the numeric keypad.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NumPadKey {
+    /// Insert the digit.
+    Digit(char),
+    /// Insert the decimal point, only in the amount mode.
+    Point,
+    /// Remove the last character.
+    Backspace,
+    /// Commit the text.
+    Ok,
+}
+
+/// The result of a numeric keypad.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumPadResult {
+    /// The text was committed with the OK key.
+    Committed(String),
+    /// The text was discarded with `Key::Backspace` on an empty text.
+    Cancelled,
+}
+
+/// A compact 0-9 keypad for entering PINs and values.
+#[derive(Debug)]
+pub struct NumPad {
+    text: String,
+    max_length: usize,
+    masked: bool,
+    decimals: Option<usize>,
+    nav: GridNav,
+}
+
+impl NumPad {
+    /// Construct a new keypad with an empty text.
+    pub fn new(max_length: usize) -> Self {
+        Self {
+            text: String::new(),
+            max_length,
+            masked: false,
+            decimals: None,
+            nav: GridNav::new(),
+        }
+    }
+
+    /// Mask the entered digits on display, usually for PINs.
+    pub fn with_masked(mut self) -> Self {
+        self.masked = true;
+        self
+    }
+
+    /// Switch to the amount mode with a decimal point key and at most `decimals` digits
+    /// after the point, the display is formatted with thousands separators.
+    pub fn with_amount(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Returns the entered text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the maximum number of characters of the text.
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    /// Returns true if the entered digits are masked on display.
+    pub fn is_masked(&self) -> bool {
+        self.masked
+    }
+
+    /// Returns true if in the amount mode.
+    pub fn is_amount(&self) -> bool {
+        self.decimals.is_some()
+    }
+
+    /// Returns the text for display, masked or formatted.
+    pub fn display_text(&self) -> String {
+        if self.masked {
+            return "*".repeat(self.text.len());
+        }
+        if !self.is_amount() {
+            return self.text.clone();
+        }
+        let (int, frac) = match self.text.find('.') {
+            Some(i) => self.text.split_at(i),
+            None => (self.text.as_str(), ""),
+        };
+        let mut grouped = String::new();
+        for (i, c) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(c);
+        }
+        grouped + frac
+    }
+
+    /// Remove all the entered text.
+    pub fn clear(&mut self) {
+        self.text.clear();
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        4
+    }
+
+    /// Returns the keys at `row`.
+    pub fn row_keys(&self, row: usize) -> Vec<NumPadKey> {
+        match row {
+            0..=2 => (1..=3)
+                .map(|i| NumPadKey::Digit((b'0' + (row * 3 + i) as u8) as char))
+                .collect(),
+            3 if self.is_amount() => vec![
+                NumPadKey::Backspace,
+                NumPadKey::Digit('0'),
+                NumPadKey::Point,
+                NumPadKey::Ok,
+            ],
+            3 => vec![NumPadKey::Backspace, NumPadKey::Digit('0'), NumPadKey::Ok],
+            _ => vec![],
+        }
+    }
+
+    fn row_lens(&self) -> Vec<usize> {
+        (0..self.rows())
+            .map(|row| self.row_keys(row).len())
+            .collect()
+    }
+
+    /// Returns the `selected` cell as `(row, col)`.
+    pub fn selected(&self) -> (usize, usize) {
+        self.nav.selected()
+    }
+
+    /// Returns the `selected` key.
+    pub fn selected_key(&self) -> NumPadKey {
+        let (row, col) = self.nav.selected();
+        self.row_keys(row)[col]
+    }
+
+    /// Move the selection up, wrap to the bottom row.
+    pub fn select_up(&mut self) {
+        self.nav.select_up(&self.row_lens());
+    }
+
+    /// Move the selection down, wrap to the top row.
+    pub fn select_down(&mut self) {
+        self.nav.select_down(&self.row_lens());
+    }
+
+    /// Move the selection left, wrap to the end of the row.
+    pub fn select_left(&mut self) {
+        self.nav.select_left(&self.row_lens());
+    }
+
+    /// Move the selection right, wrap to the start of the row.
+    pub fn select_right(&mut self) {
+        self.nav.select_right(&self.row_lens());
+    }
+
+    /// Appends a digit if the text is not full.
+    ///
+    /// In the amount mode, the leading zero is replaced and the digits after the point are limited.
+    pub fn push(&mut self, c: char) {
+        if !c.is_ascii_digit() || self.text.len() >= self.max_length {
+            return;
+        }
+        if let Some(decimals) = self.decimals {
+            if self.text == "0" {
+                self.text.clear();
+            }
+            if let Some(i) = self.text.find('.') {
+                if self.text.len() - i > decimals {
+                    return;
+                }
+            }
+        }
+        self.text.push(c);
+    }
+
+    /// Appends the decimal point in the amount mode if not present.
+    pub fn push_point(&mut self) {
+        if !matches!(self.decimals, Some(d) if d > 0) || self.text.contains('.') {
+            return;
+        }
+        if self.text.is_empty() {
+            self.text.push('0');
+        }
+        if self.text.len() < self.max_length {
+            self.text.push('.');
+        }
+    }
+
+    /// Removes the last character.
+    pub fn backspace(&mut self) {
+        self.text.pop();
+    }
+
+    /// Press the `selected` key, returns the result if the OK pressed.
+    pub fn press(&mut self) -> Option<NumPadResult> {
+        match self.selected_key() {
+            NumPadKey::Digit(c) => self.push(c),
+            NumPadKey::Point => self.push_point(),
+            NumPadKey::Backspace => self.backspace(),
+            NumPadKey::Ok => return Some(NumPadResult::Committed(self.text.clone())),
+        }
+        None
+    }
+}
+
+/// A numeric keypad input controller.
+///
+/// `Key::Backspace` removes the last character, or cancels the keypad if the text is empty.
+#[derive(Debug)]
+pub struct NumPadInputCtrl;
+
+impl Default for NumPadInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumPadInputCtrl {
+    /// Construct a new input controller for numeric keypad.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Processing input events, returns the result if the OK pressed or cancelled.
+    pub fn process(self, ctx: &Context, np: &mut NumPad) -> Option<NumPadResult> {
+        let input = ctx.input();
+        if input.is_key_pressed(Key::Up) {
+            np.select_up();
+        }
+        if input.is_key_pressed(Key::Down) {
+            np.select_down();
+        }
+        if input.is_key_pressed(Key::Left) {
+            np.select_left();
+        }
+        if input.is_key_pressed(Key::Right) {
+            np.select_right();
+        }
+        if input.is_key_pressed(Key::Backspace) {
+            if np.text().is_empty() {
+                return Some(NumPadResult::Cancelled);
+            }
+            np.backspace();
+        }
+        if input.is_key_pressed(Key::Enter) {
+            return np.press();
+        }
+        None
+    }
+}
+
+/// A numeric keypad presenter.
+#[derive(Debug)]
+pub struct NumPadPresenter {
+    row_height: f32,
+}
+
+impl Default for NumPadPresenter {
+    fn default() -> Self {
+        Self::new(32.0)
+    }
+}
+
+impl NumPadPresenter {
+    /// Construct a new presenter for numeric keypad.
+    pub fn new(row_height: f32) -> Self {
+        Self { row_height }
+    }
+
+    fn caption(key: NumPadKey) -> String {
+        match key {
+            NumPadKey::Digit(c) => c.to_string(),
+            NumPadKey::Point => ".".into(),
+            NumPadKey::Backspace => "<-".into(),
+            NumPadKey::Ok => "OK".into(),
+        }
+    }
+
+    /// Present the display and the keys on the `ctx`.
+    pub fn present(self, ctx: &mut Context, np: &NumPad) {
+        // Save current window states
+        let spacing = *ctx.style().window().spacing();
+        let padding = *ctx.style().window().padding();
+        // Remove spacing and padding
+        ctx.style_mut().window_mut().set_spacing(vec2(0.0, 0.0));
+        ctx.style_mut().window_mut().set_padding(vec2(0.0, 0.0));
+        let selected_bg_color = ctx.style().window().background().inverted();
+        let selected_fg_color = ctx.style().text().color.inverted();
+        let border_color = ctx.style().text().color;
+        // Render the display, right aligned as a calculator
+        ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &[1.0]);
+        let bounds = ctx.widget_bounds();
+        if let Some(canvas) = ctx.window_get_canvas_mut() {
+            canvas.stroke_rect(bounds, 0.0, 1.0, border_color);
+        }
+        ctx.label(
+            format!("{} ", np.display_text()).into(),
+            FlagsBuilder::align().right().middle().into(),
+        );
+        // Render each row of keys
+        let (sel_row, sel_col) = np.selected();
+        for row in 0..np.rows() {
+            let keys = np.row_keys(row);
+            let ratios = vec![1.0 / keys.len() as f32; keys.len()];
+            ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &ratios);
+            for (col, key) in keys.into_iter().enumerate() {
+                let bounds = ctx.widget_bounds();
+                let caption = Self::caption(key);
+                if row == sel_row && col == sel_col {
+                    if let Some(canvas) = ctx.window_get_canvas_mut() {
+                        canvas.fill_rect(bounds, 0.0, selected_bg_color);
+                    }
+                    ctx.label_colored(
+                        caption.into(),
+                        FlagsBuilder::align().centered().middle().into(),
+                        selected_fg_color,
+                    );
+                } else {
+                    if let Some(canvas) = ctx.window_get_canvas_mut() {
+                        canvas.stroke_rect(bounds, 0.0, 1.0, border_color);
+                    }
+                    ctx.label(
+                        caption.into(),
+                        FlagsBuilder::align().centered().middle().into(),
+                    );
+                }
+            }
+        }
+        // Restore old window states
+        ctx.style_mut().window_mut().set_spacing(spacing);
+        ctx.style_mut().window_mut().set_padding(padding);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_pad() {
+        let mut np = NumPad::new(4).with_masked();
+        assert_eq!(np.selected_key(), NumPadKey::Digit('1'));
+        np.press();
+        np.select_down();
+        np.select_right();
+        np.press();
+        np.select_up();
+        np.select_up();
+        assert_eq!(np.selected_key(), NumPadKey::Digit('0'));
+        np.press();
+        assert_eq!(np.text(), "150");
+        assert_eq!(np.display_text(), "***");
+        np.press();
+        np.press();
+        assert_eq!(np.text(), "1500");
+        np.select_left();
+        assert_eq!(np.press(), None);
+        assert_eq!(np.text(), "150");
+        np.select_left();
+        assert_eq!(np.selected_key(), NumPadKey::Ok);
+        assert_eq!(np.press(), Some(NumPadResult::Committed("150".into())));
+
+        // Amount mode with the decimal point.
+        let mut np = NumPad::new(12).with_amount(2);
+        np.push_point();
+        assert_eq!(np.text(), "0.");
+        np.clear();
+        for c in "001234567".chars() {
+            np.push(c);
+        }
+        np.push_point();
+        np.push_point();
+        for c in "891".chars() {
+            np.push(c);
+        }
+        // The third digit after the point is rejected before the max length.
+        assert_eq!(np.text(), "1234567.89");
+        assert!(np.text().len() < np.max_length());
+        assert_eq!(np.display_text(), "1,234,567.89");
+        np.select_up();
+        assert_eq!(np.row_keys(3).len(), 4);
+        np.select_right();
+        np.select_right();
+        assert_eq!(np.selected_key(), NumPadKey::Point);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{rect, Context, FlagsBuilder, Key, LayoutFormat, Rect, Vec2};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// A dialog to show the progress of a long-running operation.
+///
+/// The dialog is just view state, the caller updates it each frame and stops
+/// presenting it once the operation completed.
+#[derive(Debug)]
+pub struct ProgressDialog {
+    title: String,
+    status: String,
+    progress: Option<f32>,
+    cancellable: bool,
+    cancelled: Arc<AtomicBool>,
+    started: Instant,
+}
+
+impl ProgressDialog {
+    /// Construct a new indeterminate progress dialog.
+    ///
+    /// The `title` is also used as the window name, so it should be unique among the windows.
+    pub fn new<T: Into<String>>(title: T) -> Self {
+        Self {
+            title: title.into(),
+            status: String::new(),
+            progress: None,
+            cancellable: false,
+            cancelled: Arc::new(AtomicBool::new(false)),
+            started: Instant::now(),
+        }
+    }
+
+    /// Show a Cancel button on the dialog.
+    pub fn with_cancel(mut self) -> Self {
+        self.cancellable = true;
+        self
+    }
+
+    /// Returns the title of the dialog.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the status line of the dialog.
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /// Set the status line of the dialog.
+    pub fn set_status<S: Into<String>>(&mut self, status: S) {
+        self.status = status.into();
+    }
+
+    /// Returns the progress in `0.0..=1.0`, `None` if indeterminate.
+    pub fn progress(&self) -> Option<f32> {
+        self.progress
+    }
+
+    /// Set the determinate progress, clamped to `0.0..=1.0`.
+    pub fn set_progress(&mut self, progress: f32) {
+        self.progress = Some(progress.clamp(0.0, 1.0));
+    }
+
+    /// Switch to the indeterminate mode, a spinner is shown instead of the progress.
+    pub fn set_indeterminate(&mut self) {
+        self.progress = None;
+    }
+
+    /// Returns true if the dialog has a Cancel button.
+    pub fn is_cancellable(&self) -> bool {
+        self.cancellable
+    }
+
+    /// Request to cancel the operation.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true if the cancellation requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns the cancellation flag, suitable for handing to a worker thread.
+    pub fn cancel_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.cancelled)
+    }
+}
+
+/// A progress dialog input controller.
+///
+/// Both `Key::Enter` and `Key::Backspace` press the Cancel button.
+#[derive(Debug)]
+pub struct ProgressDialogInputCtrl;
+
+impl Default for ProgressDialogInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressDialogInputCtrl {
+    /// Construct a new input controller for progress dialog.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Processing input events.
+    pub fn process(self, ctx: &Context, pd: &ProgressDialog) {
+        let input = ctx.input();
+        if pd.is_cancellable()
+            && (input.is_key_pressed(Key::Enter) || input.is_key_pressed(Key::Backspace))
+        {
+            pd.cancel();
+        }
+    }
+}
+
+/// A progress dialog presenter.
+#[derive(Debug)]
+pub struct ProgressDialogPresenter {
+    size: Vec2,
+    row_height: f32,
+}
+
+impl Default for ProgressDialogPresenter {
+    fn default() -> Self {
+        Self::new(Vec2 { x: 400.0, y: 160.0 }, 32.0)
+    }
+}
+
+impl ProgressDialogPresenter {
+    /// Construct a new presenter for progress dialog with the window `size`.
+    pub fn new(size: Vec2, row_height: f32) -> Self {
+        Self { size, row_height }
+    }
+
+    // Draw the progress bar in the next cell of the row.
+    fn present_bar(&self, ctx: &mut Context, pd: &ProgressDialog) {
+        let bounds = ctx.widget_bounds();
+        let color = ctx.style().text().color;
+        let inner = rect(
+            bounds.x + 2.0,
+            bounds.y + 2.0,
+            bounds.w - 4.0,
+            bounds.h - 4.0,
+        );
+        let (x, w) = match pd.progress() {
+            Some(v) => (inner.x, inner.w * v),
+            None => {
+                // Bounce a quarter of the bar in every two seconds
+                let t = pd.started.elapsed().as_secs_f32() % 2.0;
+                let t = if t > 1.0 { 2.0 - t } else { t };
+                (inner.x + inner.w * 0.75 * t, inner.w * 0.25)
+            }
+        };
+        if let Some(canvas) = ctx.window_get_canvas_mut() {
+            canvas.stroke_rect(bounds, 0.0, 1.0, color);
+            canvas.fill_rect(rect(x, inner.y, w, inner.h), 0.0, color);
+        }
+        ctx.spacing(1);
+    }
+
+    /// Present the progress dialog as a window centered in the `area`.
+    ///
+    /// Should be called after the other windows to keep it above them.
+    pub fn present(self, ctx: &mut Context, pd: &ProgressDialog, area: Rect) {
+        let bounds = rect(
+            area.x + (area.w - self.size.x) / 2.0,
+            area.y + (area.h - self.size.y) / 2.0,
+            self.size.x,
+            self.size.y,
+        );
+        if ctx.begin(
+            pd.title().into(),
+            bounds,
+            FlagsBuilder::panel()
+                .border()
+                .title()
+                .no_scroll_bar()
+                .into(),
+        ) {
+            ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &[1.0]);
+            ctx.label(
+                pd.status().into(),
+                FlagsBuilder::align().left().middle().into(),
+            );
+            ctx.layout_row(LayoutFormat::Dynamic, self.row_height / 2.0, &[1.0]);
+            self.present_bar(ctx, pd);
+            if pd.is_cancellable() {
+                ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &[0.3, 0.4, 0.3]);
+                ctx.spacing(1);
+                if pd.is_cancelled() {
+                    ctx.label(
+                        "Cancelling...".into(),
+                        FlagsBuilder::align().centered().middle().into(),
+                    );
+                } else {
+                    // The Cancel button is always selected
+                    let bounds = ctx.widget_bounds();
+                    let selected_bg_color = ctx.style().window().background().inverted();
+                    if let Some(canvas) = ctx.window_get_canvas_mut() {
+                        canvas.fill_rect(bounds, 0.0, selected_bg_color);
+                    }
+                    ctx.label_colored(
+                        "Cancel".into(),
+                        FlagsBuilder::align().centered().middle().into(),
+                        ctx.style().text().color.inverted(),
+                    );
+                }
+                ctx.spacing(1);
+            }
+        }
+        ctx.end();
+        // Keep the dialog above the caller's windows
+        ctx.window_set_focus(pd.title().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_dialog() {
+        let mut pd = ProgressDialog::new("Copying").with_cancel();
+        assert_eq!(pd.progress(), None);
+        pd.set_progress(1.5);
+        assert_eq!(pd.progress(), Some(1.0));
+        pd.set_progress(-0.5);
+        assert_eq!(pd.progress(), Some(0.0));
+        pd.set_indeterminate();
+        assert_eq!(pd.progress(), None);
+
+        let cancelled = pd.cancel_flag();
+        let worker = std::thread::spawn(move || {
+            while !cancelled.load(Ordering::SeqCst) {
+                std::thread::yield_now();
+            }
+        });
+        assert!(!pd.is_cancelled());
+        pd.cancel();
+        worker.join().unwrap();
+        assert!(pd.is_cancelled());
+    }
+}
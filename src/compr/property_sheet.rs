@@ -5,6 +5,9 @@ use std::cell::{Cell, Ref, RefCell, RefMut, UnsafeCell};
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use super::{
+    VirtualKeyboard, VirtualKeyboardInputCtrl, VirtualKeyboardPresenter, VirtualKeyboardResult,
+};
 use crate::{
     color_rgba, rect, vec2, Context, FlagsBuilder, Key, LayoutFormat, Rect, StyleButton, StyleItem,
     SymbolType, Vec2,
@@ -1372,6 +1375,7 @@ type PropertyItem = Arc<dyn Property + Send + Sync>;
 #[derive(Default)]
 pub struct PropertySheet {
     items: Vec<PropertyItem>,
+    editor: Option<(usize, VirtualKeyboard)>,
 }
 
 impl Debug for PropertySheet {
@@ -1383,7 +1387,10 @@ impl Debug for PropertySheet {
 impl PropertySheet {
     /// Create a new property sheet.
     pub fn new() -> Self {
-        Self { items: vec![] }
+        Self {
+            items: vec![],
+            editor: None,
+        }
     }
 
     /// Create a new property sheet with items.
@@ -1391,7 +1398,10 @@ impl PropertySheet {
         for (i, p) in items.iter().enumerate() {
             p.set_id(i);
         }
-        Self { items }
+        Self {
+            items,
+            editor: None,
+        }
     }
 
     /// Append a property to the sheet.
@@ -1669,6 +1679,43 @@ impl PropertySheet {
         }
     }
 
+    /// Start editing the `selected` string property with a [`VirtualKeyboard`].
+    ///
+    /// Returns false if the `selected` item is not a string property.
+    pub fn begin_edit(&mut self) -> bool {
+        let editor = self.current_selected().and_then(|p| {
+            p.as_property_string().map(|ps| {
+                (
+                    p.id(),
+                    VirtualKeyboard::with_text(&*ps.value(), ps.max_length()),
+                )
+            })
+        });
+        self.editor = editor;
+        self.editor.is_some()
+    }
+
+    /// Finish editing, the value of the edited property is updated if `result` committed.
+    pub fn end_edit(&mut self, result: VirtualKeyboardResult) {
+        if let Some((id, _)) = self.editor.take() {
+            if let VirtualKeyboardResult::Committed(value) = result {
+                if let Some(p) = self.get(id).and_then(|p| p.as_property_string()) {
+                    p.set_value(&value);
+                }
+            }
+        }
+    }
+
+    /// Returns the editor if a string property is being edited.
+    pub fn editor(&self) -> Option<&VirtualKeyboard> {
+        self.editor.as_ref().map(|(_, vk)| vk)
+    }
+
+    /// Returns the mutable editor if a string property is being edited.
+    pub fn editor_mut(&mut self) -> Option<&mut VirtualKeyboard> {
+        self.editor.as_mut().map(|(_, vk)| vk)
+    }
+
     /// Add a Action Button to the sheet.
     pub fn action_button<F>(&mut self, name: &'static str, text: &'static str, f: Arc<RefCell<F>>)
     where
@@ -1809,9 +1856,22 @@ impl PropertySheetInputCtrl {
 
     /// Processing Input Events.
     pub fn process(self, ctx: &Context, ps: &mut PropertySheet) {
+        // The editor takes all input events while editing
+        if let Some(vk) = ps.editor_mut() {
+            if let Some(result) = VirtualKeyboardInputCtrl::new().process(ctx, vk) {
+                ps.end_edit(result);
+            }
+            return;
+        }
         let input = ctx.input();
         if input.is_key_pressed(Key::Enter) {
-            // FIXME:
+            // Edit string with the virtual keyboard if no physical keyboard
+            #[cfg(feature = "keyboard-only")]
+            {
+                if ps.begin_edit() {
+                    return;
+                }
+            }
         }
         if input.is_key_pressed(Key::Up) {
             ps.select_prev_wrapped();
@@ -2267,6 +2327,7 @@ impl PropertyPresenter {
 #[derive(Debug)]
 pub struct PropertySheetPresenter {
     row_height: f32,
+    header_height: f32,
 }
 
 impl Default for PropertySheetPresenter {
@@ -2278,11 +2339,21 @@ impl Default for PropertySheetPresenter {
 impl PropertySheetPresenter {
     /// Construct a property presenter with specified `row_height`.
     pub fn new(row_height: f32) -> Self {
-        Self { row_height }
+        Self {
+            row_height,
+            header_height: 0.0,
+        }
+    }
+
+    /// Set the height of the rows presented above the items in the same window,
+    /// e.g. a tab bar, to keep the selected item visible.
+    pub fn with_header_height(mut self, height: f32) -> Self {
+        self.header_height = height;
+        self
     }
 
     fn scroll_to_selected(&self, ctx: &'_ mut Context, ps: &'_ PropertySheet) {
-        let mut y: i32 = 0;
+        let mut y: i32 = self.header_height as i32;
         for p in ps.iter().filter(|x| x.is_visible()) {
             if p.widget_type() == WidgetType::Separator {
                 y += (self.row_height / 2.0) as i32;
@@ -2304,6 +2375,11 @@ impl PropertySheetPresenter {
 
     /// Present all items of the property sheet in `ctx`.
     pub fn present(self, ctx: &'_ mut Context, ps: &'_ PropertySheet) {
+        // Present the editor instead of the items while editing
+        if let Some(vk) = ps.editor() {
+            VirtualKeyboardPresenter::new(self.row_height).present(ctx, vk);
+            return;
+        }
         // Save current window states
         let spacing = *ctx.style().window().spacing();
         let padding = *ctx.style().window().padding();
@@ -2492,4 +2568,28 @@ mod tests {
             "Failure!"
         );
     }
+
+    #[test]
+    fn test_property_sheet_edit() {
+        let mut ps = PropertySheet::new();
+        ps.switch("Switch", false);
+        ps.text_box("Name", 8, "nuki");
+        ps.select_items(&[0]);
+        assert!(!ps.begin_edit());
+        assert!(ps.editor().is_none());
+
+        ps.select_items(&[1]);
+        assert!(ps.begin_edit());
+        assert_eq!(ps.editor().unwrap().text(), "nuki");
+        ps.editor_mut().unwrap().push('!');
+        ps.end_edit(VirtualKeyboardResult::Cancelled);
+        assert!(ps.editor().is_none());
+        assert_eq!(ps.get_value_string_of("Name").unwrap().deref(), "nuki");
+
+        assert!(ps.begin_edit());
+        ps.editor_mut().unwrap().push('!');
+        let text = ps.editor().unwrap().text().to_string();
+        ps.end_edit(VirtualKeyboardResult::Committed(text));
+        assert_eq!(ps.get_value_string_of("Name").unwrap().deref(), "nuki!");
+    }
 }
@@ -0,0 +1,437 @@
+use crate::{
+    rect, vec2, Color, CommandBuffer, Context, FlagsBuilder, LayoutFormat, Rect, SymbolType,
+};
+use chrono::Local;
+use std::fmt::Debug;
+
+/// The alignment of a segment in the status bar.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatusAlign {
+    Left,
+    Center,
+    Right,
+}
+
+/// The content of a segment in the status bar.
+pub enum StatusContent {
+    /// A static text.
+    Text(String),
+    /// A text evaluated in every frame.
+    Dynamic(Box<dyn Fn() -> String>),
+    /// A small symbol followed by a text.
+    Symbol(SymbolType, String),
+}
+
+impl Debug for StatusContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatusContent::Text(s) => f.debug_tuple("Text").field(s).finish(),
+            StatusContent::Dynamic(_) => f.write_str("Dynamic"),
+            StatusContent::Symbol(sym, s) => f.debug_tuple("Symbol").field(sym).field(s).finish(),
+        }
+    }
+}
+
+impl StatusContent {
+    /// Construct a static text content.
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        StatusContent::Text(text.into())
+    }
+
+    /// Construct a content evaluating the `f` in every frame.
+    pub fn dynamic<F: Fn() -> String + 'static>(f: F) -> Self {
+        StatusContent::Dynamic(Box::new(f))
+    }
+
+    /// Construct a symbol and text content.
+    pub fn symbol<S: Into<String>>(symbol: SymbolType, text: S) -> Self {
+        StatusContent::Symbol(symbol, text.into())
+    }
+
+    /// Construct a content of the current local time, such as `"%H:%M"`.
+    ///
+    /// See [`chrono::format::strftime`] for the supported specifiers.
+    pub fn clock<S: Into<String>>(format: S) -> Self {
+        let format = format.into();
+        Self::dynamic(move || Local::now().format(&format).to_string())
+    }
+
+    /// Returns the symbol of the content.
+    pub fn symbol_type(&self) -> Option<SymbolType> {
+        match self {
+            StatusContent::Symbol(sym, _) => Some(*sym),
+            _ => None,
+        }
+    }
+
+    /// Returns the text of the content for the current frame.
+    pub fn to_text(&self) -> String {
+        match self {
+            StatusContent::Text(s) | StatusContent::Symbol(_, s) => s.clone(),
+            StatusContent::Dynamic(f) => f(),
+        }
+    }
+}
+
+/// A segment of the status bar.
+#[derive(Debug)]
+pub struct StatusSegment {
+    id: String,
+    align: StatusAlign,
+    content: StatusContent,
+    visible: bool,
+}
+
+impl StatusSegment {
+    /// Returns the id of the segment.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the alignment of the segment.
+    pub fn align(&self) -> StatusAlign {
+        self.align
+    }
+
+    /// Returns the content of the segment.
+    pub fn content(&self) -> &StatusContent {
+        &self.content
+    }
+
+    /// Returns true if the segment is shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
+/// A one-line bar of the segments aligned to the left, center or right.
+#[derive(Debug, Default)]
+pub struct StatusBar {
+    segments: Vec<StatusSegment>,
+}
+
+impl StatusBar {
+    /// Construct a new empty status bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a segment, the segments of the same alignment are laid out in order.
+    ///
+    /// The segment with the same `id` is replaced.
+    pub fn push<S: Into<String>>(&mut self, id: S, align: StatusAlign, content: StatusContent) {
+        let segment = StatusSegment {
+            id: id.into(),
+            align,
+            content,
+            visible: true,
+        };
+        match self.segments.iter_mut().find(|s| s.id == segment.id) {
+            Some(s) => *s = segment,
+            None => self.segments.push(segment),
+        }
+    }
+
+    /// Remove the segment by `id`.
+    pub fn remove(&mut self, id: &str) -> Option<StatusSegment> {
+        let index = self.segments.iter().position(|s| s.id == id)?;
+        Some(self.segments.remove(index))
+    }
+
+    /// Returns true if the status bar no segments.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Returns the number of segments in the status bar.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the segment by `id`.
+    pub fn find(&self, id: &str) -> Option<&StatusSegment> {
+        self.segments.iter().find(|s| s.id == id)
+    }
+
+    /// Returns an iterator over the segments.
+    pub fn iter(&self) -> std::slice::Iter<'_, StatusSegment> {
+        self.segments.iter()
+    }
+
+    /// Replace the content of the segment by `id`.
+    pub fn set_content(&mut self, id: &str, content: StatusContent) {
+        if let Some(s) = self.segments.iter_mut().find(|s| s.id == id) {
+            s.content = content;
+        }
+    }
+
+    /// Show or hide the segment by `id`.
+    pub fn set_visible(&mut self, id: &str, visible: bool) {
+        if let Some(s) = self.segments.iter_mut().find(|s| s.id == id) {
+            s.visible = visible;
+        }
+    }
+
+    /// Returns true if the segment by `id` is shown.
+    pub fn is_visible(&self, id: &str) -> bool {
+        self.find(id).is_some_and(|s| s.visible)
+    }
+}
+
+/// The position of the status bar in the current window.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StatusBarPosition {
+    /// Present the status bar before the other contents of the window.
+    Top,
+    /// Present the status bar after the other contents of the window,
+    /// it's pushed down to the bottom of the window.
+    Bottom,
+}
+
+/// A status bar presenter.
+#[derive(Debug)]
+pub struct StatusBarPresenter {
+    row_height: f32,
+    position: StatusBarPosition,
+}
+
+impl Default for StatusBarPresenter {
+    fn default() -> Self {
+        Self::new(32.0)
+    }
+}
+
+impl StatusBarPresenter {
+    /// Construct a new presenter for status bar at the bottom of the window.
+    pub fn new(row_height: f32) -> Self {
+        Self {
+            row_height,
+            position: StatusBarPosition::Bottom,
+        }
+    }
+
+    /// Set the position of the status bar in the window.
+    pub fn with_position(mut self, position: StatusBarPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Returns the `(x, w)` of each segment in a bar of `width`.
+    ///
+    /// The left and right segments are kept while the center segments are elided
+    /// first when space runs out, a `w` of zero means the segment is hidden.
+    fn layout(segments: &[(StatusAlign, f32)], width: f32) -> Vec<(f32, f32)> {
+        let total = |align| -> f32 {
+            segments
+                .iter()
+                .filter(|(a, _)| *a == align)
+                .map(|(_, w)| w)
+                .sum()
+        };
+        let center = total(StatusAlign::Center);
+        let left_end = total(StatusAlign::Left).min(width);
+        let right_start = (width - total(StatusAlign::Right)).max(left_end);
+        // Centered in the bar but never overlaps the sides
+        let mut center_x =
+            ((width - center) / 2.0).clamp(left_end, (right_start - center).max(left_end));
+        let mut left_x = 0.0;
+        let mut right_x = width - total(StatusAlign::Right);
+        segments
+            .iter()
+            .map(|(align, w)| {
+                let (x, min_x, max_x) = match align {
+                    StatusAlign::Left => (&mut left_x, 0.0, left_end),
+                    StatusAlign::Center => (&mut center_x, left_end, right_start),
+                    StatusAlign::Right => (&mut right_x, left_end, width),
+                };
+                let start = x.max(min_x).min(max_x);
+                let end = (*x + w).max(start).min(max_x);
+                *x += w;
+                (start, end - start)
+            })
+            .collect()
+    }
+
+    fn present_symbol(canvas: &mut CommandBuffer, symbol: SymbolType, bounds: Rect, color: Color) {
+        let (x, y, w, h) = (bounds.x, bounds.y, bounds.w, bounds.h);
+        match symbol {
+            SymbolType::None | SymbolType::Max => {}
+            SymbolType::X => {
+                canvas.stroke_line(x, y, x + w, y + h, 2.0, color);
+                canvas.stroke_line(x, y + h, x + w, y, 2.0, color);
+            }
+            SymbolType::Underscore => canvas.stroke_line(x, y + h, x + w, y + h, 2.0, color),
+            SymbolType::CircleSolid => canvas.fill_circle(bounds, color),
+            SymbolType::CircleOutline => canvas.stroke_circle(bounds, 1.0, color),
+            SymbolType::RectSolid => canvas.fill_rect(bounds, 0.0, color),
+            SymbolType::RectOutline => canvas.stroke_rect(bounds, 0.0, 1.0, color),
+            SymbolType::TriangleUp => {
+                canvas.fill_triangle(x, y + h, x + w / 2.0, y, x + w, y + h, color)
+            }
+            SymbolType::TriangleDown => {
+                canvas.fill_triangle(x, y, x + w, y, x + w / 2.0, y + h, color)
+            }
+            SymbolType::TriangleLeft => {
+                canvas.fill_triangle(x + w, y, x + w, y + h, x, y + h / 2.0, color)
+            }
+            SymbolType::TriangleRight => {
+                canvas.fill_triangle(x, y, x + w, y + h / 2.0, x, y + h, color)
+            }
+            SymbolType::Plus => {
+                canvas.stroke_line(x + w / 2.0, y, x + w / 2.0, y + h, 2.0, color);
+                canvas.stroke_line(x, y + h / 2.0, x + w, y + h / 2.0, 2.0, color);
+            }
+            SymbolType::Minus => canvas.stroke_line(x, y + h / 2.0, x + w, y + h / 2.0, 2.0, color),
+        }
+    }
+
+    // Push the next row down to leave only a row of `row_height` in the window.
+    fn push_to_bottom(&self, ctx: &mut Context) {
+        let next_y = match ctx.window_get_panel() {
+            Some(panel) => panel.at_y() + panel.row().height(),
+            None => return,
+        };
+        let region = ctx.window_get_content_region();
+        let gap = region.y + region.h - next_y - self.row_height;
+        if gap > 0.0 {
+            ctx.layout_row(LayoutFormat::Dynamic, gap, &[1.0]);
+            ctx.spacing(1);
+        }
+    }
+
+    /// Present the status bar on the `ctx`.
+    pub fn present(self, ctx: &mut Context, sb: &StatusBar) {
+        // Save current window states
+        let spacing = *ctx.style().window().spacing();
+        let padding = *ctx.style().window().padding();
+        // Remove spacing and padding
+        ctx.style_mut().window_mut().set_spacing(vec2(0.0, 0.0));
+        ctx.style_mut().window_mut().set_padding(vec2(0.0, 0.0));
+        if self.position == StatusBarPosition::Bottom {
+            self.push_to_bottom(ctx);
+        }
+        let segments: Vec<(&StatusSegment, String)> = sb
+            .iter()
+            .filter(|s| s.is_visible())
+            .map(|s| (s, s.content().to_text()))
+            .collect();
+        let font = ctx.style().font();
+        let gap = font.height() / 2.0;
+        let widths: Vec<(StatusAlign, f32)> = segments
+            .iter()
+            .map(|(s, text)| {
+                let symbol = match s.content().symbol_type() {
+                    Some(_) => self.row_height,
+                    None => 0.0,
+                };
+                (s.align(), symbol + font.text_width(text) + gap * 2.0)
+            })
+            .collect();
+        ctx.layout_space_begin(
+            LayoutFormat::Static,
+            self.row_height,
+            segments.len() as i32 * 2,
+        );
+        let bounds = ctx.layout_space_bounds();
+        let color = ctx.style().text().color;
+        let mut separator = color;
+        separator.a /= 2;
+        if let Some(canvas) = ctx.window_get_canvas_mut() {
+            let y = match self.position {
+                StatusBarPosition::Top => bounds.y + bounds.h,
+                StatusBarPosition::Bottom => bounds.y,
+            };
+            canvas.stroke_line(bounds.x, y, bounds.x + bounds.w, y, 1.0, separator);
+        }
+        let layout = Self::layout(&widths, bounds.w);
+        for ((s, text), (x, w)) in segments.iter().zip(layout) {
+            if w <= 0.0 {
+                continue;
+            }
+            let mut x = x + gap;
+            let mut w = w - gap * 2.0;
+            if let Some(symbol) = s.content().symbol_type() {
+                let size = self.row_height.min(w) / 2.0;
+                ctx.layout_space_push(rect(x, 0.0, size * 2.0, self.row_height));
+                let cell = ctx.widget_bounds();
+                let inner = rect(
+                    cell.x + size / 2.0,
+                    cell.y + (cell.h - size) / 2.0,
+                    size,
+                    size,
+                );
+                if let Some(canvas) = ctx.window_get_canvas_mut() {
+                    Self::present_symbol(canvas, symbol, inner, color);
+                }
+                ctx.spacing(1);
+                x += size * 2.0;
+                w -= size * 2.0;
+            }
+            if w > 0.0 {
+                ctx.layout_space_push(rect(x, 0.0, w, self.row_height));
+                ctx.label(
+                    text.as_str().into(),
+                    FlagsBuilder::align().left().middle().into(),
+                );
+            }
+        }
+        ctx.layout_space_end();
+        // Restore old window states
+        ctx.style_mut().window_mut().set_spacing(spacing);
+        ctx.style_mut().window_mut().set_padding(padding);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_bar() {
+        let mut sb = StatusBar::new();
+        sb.push("hint", StatusAlign::Left, StatusContent::text("OK select"));
+        sb.push(
+            "title",
+            StatusAlign::Center,
+            StatusContent::text("Settings"),
+        );
+        sb.push("clock", StatusAlign::Right, StatusContent::clock("%H:%M"));
+        sb.push(
+            "wifi",
+            StatusAlign::Right,
+            StatusContent::symbol(SymbolType::CircleSolid, "WiFi"),
+        );
+        assert_eq!(sb.len(), 4);
+        assert_eq!(sb.find("clock").unwrap().content().to_text().len(), 5);
+        sb.set_visible("wifi", false);
+        assert!(!sb.is_visible("wifi"));
+        sb.push(
+            "hint",
+            StatusAlign::Left,
+            StatusContent::text("BACK cancel"),
+        );
+        assert_eq!(sb.len(), 4);
+        assert_eq!(sb.find("hint").unwrap().content().to_text(), "BACK cancel");
+        assert!(sb.remove("title").is_some());
+        assert!(sb.find("title").is_none());
+
+        use StatusAlign::*;
+        let segments = [(Left, 20.0), (Center, 30.0), (Right, 10.0), (Right, 20.0)];
+        let layout = StatusBarPresenter::layout(&segments, 100.0);
+        assert_eq!(
+            layout,
+            [(0.0, 20.0), (35.0, 30.0), (70.0, 10.0), (80.0, 20.0)]
+        );
+        // The center is elided first.
+        let layout = StatusBarPresenter::layout(&segments, 60.0);
+        assert_eq!(
+            layout,
+            [(0.0, 20.0), (20.0, 10.0), (30.0, 10.0), (40.0, 20.0)]
+        );
+        let layout = StatusBarPresenter::layout(&segments, 40.0);
+        assert_eq!(
+            layout,
+            [(0.0, 20.0), (20.0, 0.0), (20.0, 0.0), (20.0, 20.0)]
+        );
+    }
+}
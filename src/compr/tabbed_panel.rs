@@ -0,0 +1,431 @@
+use super::{
+    FileList, FileListInputCtrl, FileListPresenter, PropertySheet, PropertySheetInputCtrl,
+    PropertySheetPresenter,
+};
+use crate::{vec2, Context, FlagsBuilder, Key, LayoutFormat};
+use std::cell::Cell;
+use std::fmt::Debug;
+
+/// A custom page hosted by the composited presenters.
+///
+/// Any `Fn(&mut Context)` closure is a page that only presents.
+pub trait TabPage {
+    /// Processing input events.
+    fn process(&mut self, _ctx: &Context) {}
+
+    /// Present the page on the `ctx`.
+    fn present(&self, ctx: &mut Context);
+}
+
+impl<F> TabPage for F
+where
+    F: Fn(&mut Context),
+{
+    fn present(&self, ctx: &mut Context) {
+        self(ctx)
+    }
+}
+
+/// The content of a tab.
+pub enum TabContent {
+    PropertySheet(PropertySheet),
+    FileList(FileList),
+    Custom(Box<dyn TabPage>),
+}
+
+impl Debug for TabContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TabContent::PropertySheet(ps) => f.debug_tuple("PropertySheet").field(ps).finish(),
+            TabContent::FileList(fl) => f.debug_tuple("FileList").field(fl).finish(),
+            TabContent::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+impl TabContent {
+    /// Construct a custom content with the `page`.
+    pub fn custom<P: TabPage + 'static>(page: P) -> Self {
+        TabContent::Custom(Box::new(page))
+    }
+
+    pub(crate) fn process(&mut self, ctx: &Context) {
+        match self {
+            TabContent::PropertySheet(ps) => PropertySheetInputCtrl::new().process(ctx, ps),
+            TabContent::FileList(fl) => FileListInputCtrl::new().process(ctx, fl),
+            TabContent::Custom(page) => page.process(ctx),
+        }
+    }
+
+    /// Returns true if the content scrolls the window to the selected item by itself.
+    pub(crate) fn is_self_scrolled(&self) -> bool {
+        !matches!(self, TabContent::Custom(_))
+    }
+
+    pub(crate) fn present(&self, ctx: &mut Context, row_height: f32, header_height: f32) {
+        match self {
+            TabContent::PropertySheet(ps) => PropertySheetPresenter::new(row_height)
+                .with_header_height(header_height)
+                .present(ctx, ps),
+            TabContent::FileList(fl) => FileListPresenter::new(row_height)
+                .with_header_height(header_height)
+                .present(ctx, fl),
+            TabContent::Custom(page) => page.present(ctx),
+        }
+    }
+}
+
+/// A tab of the tabbed panel.
+#[derive(Debug)]
+pub struct Tab {
+    title: String,
+    content: TabContent,
+    scroll: Cell<(u32, u32)>,
+}
+
+impl Tab {
+    /// Returns the title of the tab.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the content of the tab.
+    pub fn content(&self) -> &TabContent {
+        &self.content
+    }
+
+    /// Returns the mutable content of the tab.
+    pub fn content_mut(&mut self) -> &mut TabContent {
+        &mut self.content
+    }
+}
+
+/// An ordered list of tabs, only the active one is presented.
+#[derive(Debug, Default)]
+pub struct TabbedPanel {
+    tabs: Vec<Tab>,
+    active: usize,
+    tab_bar_focused: bool,
+    presented: Cell<Option<usize>>,
+}
+
+impl TabbedPanel {
+    /// Construct a new empty tabbed panel.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a tab to the back of the panel.
+    pub fn append<S: Into<String>>(&mut self, title: S, content: TabContent) {
+        self.tabs.push(Tab {
+            title: title.into(),
+            content,
+            scroll: Cell::new((0, 0)),
+        });
+    }
+
+    /// Returns true if the panel no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Returns the number of tabs in the panel.
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Returns the tab reference at index in the panel.
+    pub fn get(&self, index: usize) -> Option<&Tab> {
+        self.tabs.get(index)
+    }
+
+    /// Returns the mutable tab reference at index in the panel.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Tab> {
+        self.tabs.get_mut(index)
+    }
+
+    /// Returns an iterator over the tabs.
+    pub fn iter(&self) -> std::slice::Iter<'_, Tab> {
+        self.tabs.iter()
+    }
+
+    /// Returns the `active` tab index.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Mark the tab at index as `active`.
+    pub fn set_active(&mut self, index: usize) {
+        if index < self.len() {
+            self.active = index;
+        }
+    }
+
+    /// Returns the `active` tab.
+    pub fn active_tab(&self) -> Option<&Tab> {
+        self.get(self.active)
+    }
+
+    /// Returns the mutable `active` tab.
+    pub fn active_tab_mut(&mut self) -> Option<&mut Tab> {
+        self.tabs.get_mut(self.active)
+    }
+
+    /// Mark `prev` tab as `active`, wrap to `last` tab when current at `first` tab.
+    pub fn select_prev_wrapped(&mut self) {
+        if self.active == 0 {
+            self.active = self.len().saturating_sub(1);
+        } else {
+            self.active -= 1;
+        }
+    }
+
+    /// Mark next tab as `active`, wrap to `first` tab when current at `last` tab.
+    pub fn select_next_wrapped(&mut self) {
+        self.active += 1;
+        if self.active >= self.len() {
+            self.active = 0;
+        }
+    }
+
+    /// Returns true if the tab bar takes the input events instead of the content.
+    pub fn is_tab_bar_focused(&self) -> bool {
+        self.tab_bar_focused
+    }
+
+    /// Focus the tab bar or the content.
+    pub fn set_tab_bar_focused(&mut self, focused: bool) {
+        self.tab_bar_focused = focused;
+    }
+}
+
+/// The keys to switch the tabs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TabSwitchKeys {
+    /// `Key::Tab` focuses the tab bar, then Left/Right switch the tabs and
+    /// Down/Enter focus the content again.
+    TabBar,
+    /// The `prev` and `next` keys switch the tabs directly.
+    Keys { prev: Key, next: Key },
+}
+
+/// A tabbed panel input controller.
+#[derive(Debug)]
+pub struct TabbedPanelInputCtrl {
+    keys: TabSwitchKeys,
+}
+
+impl Default for TabbedPanelInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TabbedPanelInputCtrl {
+    /// Construct a new input controller switching tabs with the tab bar.
+    pub fn new() -> Self {
+        Self::with_keys(TabSwitchKeys::TabBar)
+    }
+
+    /// Construct a new input controller switching tabs with the `keys`.
+    pub fn with_keys(keys: TabSwitchKeys) -> Self {
+        Self { keys }
+    }
+
+    /// Processing input events, the events not for switching are forwarded to the `active` tab.
+    pub fn process(self, ctx: &Context, tp: &mut TabbedPanel) {
+        let input = ctx.input();
+        match self.keys {
+            TabSwitchKeys::TabBar => {
+                if tp.is_tab_bar_focused() {
+                    if input.is_key_pressed(Key::Left) {
+                        tp.select_prev_wrapped();
+                    }
+                    if input.is_key_pressed(Key::Right) {
+                        tp.select_next_wrapped();
+                    }
+                    if input.is_key_pressed(Key::Down)
+                        || input.is_key_pressed(Key::Enter)
+                        || input.is_key_pressed(Key::Tab)
+                    {
+                        tp.set_tab_bar_focused(false);
+                    }
+                    return;
+                }
+                if input.is_key_pressed(Key::Tab) {
+                    tp.set_tab_bar_focused(true);
+                    return;
+                }
+            }
+            TabSwitchKeys::Keys { prev, next } => {
+                if input.is_key_pressed(prev) {
+                    tp.select_prev_wrapped();
+                    return;
+                }
+                if input.is_key_pressed(next) {
+                    tp.select_next_wrapped();
+                    return;
+                }
+            }
+        }
+        if let Some(tab) = tp.active_tab_mut() {
+            tab.content.process(ctx);
+        }
+    }
+}
+
+/// A tabbed panel presenter.
+#[derive(Debug)]
+pub struct TabbedPanelPresenter {
+    row_height: f32,
+}
+
+impl Default for TabbedPanelPresenter {
+    fn default() -> Self {
+        Self::new(32.0)
+    }
+}
+
+impl TabbedPanelPresenter {
+    /// Construct a new presenter for tabbed panel.
+    pub fn new(row_height: f32) -> Self {
+        Self { row_height }
+    }
+
+    fn present_tab_bar(&self, ctx: &mut Context, tp: &TabbedPanel) {
+        // Save current window states
+        let spacing = *ctx.style().window().spacing();
+        let padding = *ctx.style().window().padding();
+        // Remove spacing and padding
+        ctx.style_mut().window_mut().set_spacing(vec2(0.0, 0.0));
+        ctx.style_mut().window_mut().set_padding(vec2(0.0, 0.0));
+        let selected_bg_color = ctx.style().window().background().inverted();
+        let selected_fg_color = ctx.style().text().color.inverted();
+        let border_color = ctx.style().text().color;
+        let ratios = vec![1.0 / tp.len() as f32; tp.len()];
+        ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &ratios);
+        for (i, tab) in tp.iter().enumerate() {
+            let bounds = ctx.widget_bounds();
+            if tp.active() == i {
+                if let Some(canvas) = ctx.window_get_canvas_mut() {
+                    canvas.fill_rect(bounds, 0.0, selected_bg_color);
+                    if tp.is_tab_bar_focused() {
+                        canvas.stroke_rect(bounds, 0.0, 2.0, border_color);
+                    }
+                }
+                ctx.label_colored(
+                    tab.title().into(),
+                    FlagsBuilder::align().centered().middle().into(),
+                    selected_fg_color,
+                );
+            } else {
+                if let Some(canvas) = ctx.window_get_canvas_mut() {
+                    canvas.stroke_rect(bounds, 0.0, 1.0, border_color);
+                }
+                ctx.label(
+                    tab.title().into(),
+                    FlagsBuilder::align().centered().middle().into(),
+                );
+            }
+        }
+        // Restore old window states
+        ctx.style_mut().window_mut().set_spacing(spacing);
+        ctx.style_mut().window_mut().set_padding(padding);
+    }
+
+    /// Present the tab bar and the `active` tab on the `ctx`.
+    ///
+    /// The scroll of the custom pages is kept by each tab, the built-in presenters
+    /// scroll to their selected item below the tab bar instead.
+    pub fn present(self, ctx: &mut Context, tp: &TabbedPanel) {
+        let tab = match tp.active_tab() {
+            Some(tab) => tab,
+            None => return,
+        };
+        let switched = tp.presented.replace(Some(tp.active())) != Some(tp.active());
+        let self_scrolled = tab.content.is_self_scrolled();
+        // Restore the scroll of the tab switched to
+        if switched && !self_scrolled {
+            let (x, y) = tab.scroll.get();
+            ctx.window_set_scroll(x, y);
+        }
+        self.present_tab_bar(ctx, tp);
+        tab.content.present(ctx, self.row_height, self.row_height);
+        if !self_scrolled {
+            tab.scroll.set(ctx.window_get_scroll());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tabbed_panel() {
+        let mut tp = TabbedPanel::new();
+        let mut video = PropertySheet::new();
+        video.switch("HDR", false);
+        tp.append("Video", TabContent::PropertySheet(video));
+        tp.append("Audio", TabContent::PropertySheet(PropertySheet::new()));
+        tp.append("About", TabContent::custom(|_ctx: &mut Context| {}));
+        assert_eq!(tp.len(), 3);
+        assert_eq!(tp.active_tab().unwrap().title(), "Video");
+
+        tp.select_prev_wrapped();
+        assert_eq!(tp.active(), 2);
+        tp.select_next_wrapped();
+        assert_eq!(tp.active(), 0);
+        tp.set_active(3);
+        assert_eq!(tp.active(), 0);
+
+        // The content state is kept by the tab.
+        if let TabContent::PropertySheet(ps) = tp.active_tab_mut().unwrap().content_mut() {
+            ps.select_items(&[0]);
+        }
+        tp.set_active(1);
+        tp.set_active(0);
+        match tp.active_tab().unwrap().content() {
+            TabContent::PropertySheet(ps) => assert_eq!(ps.selected_items(), [0]),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_tabbed_panel_file_list_scroll() {
+        let dir = std::env::temp_dir().join(format!("nuki-tabbed-panel-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for i in 0..20 {
+            std::fs::write(dir.join(format!("{:02}.txt", i)), "").unwrap();
+        }
+
+        let mut tp = TabbedPanel::new();
+        tp.append("Files", TabContent::FileList(FileList::new(&dir, "txt")));
+        tp.append("About", TabContent::custom(|_ctx: &mut Context| {}));
+        assert!(tp.get(0).unwrap().content().is_self_scrolled());
+        assert!(!tp.get(1).unwrap().content().is_self_scrolled());
+
+        if let TabContent::FileList(fl) = tp.active_tab_mut().unwrap().content_mut() {
+            for _ in 0..15 {
+                fl.select_next();
+            }
+        }
+        tp.select_next_wrapped();
+        tp.select_prev_wrapped();
+
+        // The selection is kept by the tab, and the scroll counts in the tab bar row.
+        match tp.active_tab().unwrap().content() {
+            TabContent::FileList(fl) => {
+                assert_eq!(fl.selected(), 15);
+                let presenter = FileListPresenter::new(32.0);
+                assert_eq!(presenter.scroll_offset(fl, 320.0), 32 * 16 - 320 + 64);
+                let presenter = presenter.with_header_height(32.0);
+                assert_eq!(presenter.scroll_offset(fl, 320.0), 32 * 17 - 320 + 64);
+                assert_eq!(presenter.scroll_offset(fl, 1024.0), 0);
+            }
+            _ => unreachable!(),
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,529 @@
+use crate::{vec2, Context, Flags, Key, LayoutFormat, TextAlignment};
+use std::cell::{Ref, RefCell};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// The direction of the sorting.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+type Compare<R> = Box<dyn Fn(&R, &R) -> Ordering>;
+
+/// A column of the table view.
+pub struct Column<R> {
+    title: String,
+    ratio: f32,
+    align: TextAlignment,
+    cell: Box<dyn Fn(&R) -> String>,
+    compare: Option<Compare<R>>,
+}
+
+impl<R> Debug for Column<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Column")
+            .field("title", &self.title)
+            .field("ratio", &self.ratio)
+            .field("sortable", &self.is_sortable())
+            .finish()
+    }
+}
+
+impl<R> Column<R> {
+    /// Construct a new left aligned column with the width `ratio` and the `cell` text.
+    pub fn new<S, F>(title: S, ratio: f32, cell: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&R) -> String + 'static,
+    {
+        Self {
+            title: title.into(),
+            ratio,
+            align: TextAlignment::Left,
+            cell: Box::new(cell),
+            compare: None,
+        }
+    }
+
+    /// Set the alignment of the cells.
+    pub fn with_align(mut self, align: TextAlignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Make the column sortable with the `key` of the rows.
+    pub fn with_sort_key<K, F>(mut self, key: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&R) -> K + 'static,
+    {
+        self.compare = Some(Box::new(move |a, b| key(a).cmp(&key(b))));
+        self
+    }
+
+    /// Returns the title of the column.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns true if the column has a sort key.
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// Returns the cell text of the `row`.
+    pub fn cell(&self, row: &R) -> String {
+        (self.cell)(row)
+    }
+}
+
+/// The cached display order of the rows.
+#[derive(Debug, Default)]
+struct RowOrder {
+    indices: Vec<usize>,
+    sort: Option<(usize, SortOrder)>,
+    generation: u64,
+}
+
+/// A table of rows with typed columns.
+pub struct TableView<R> {
+    columns: Vec<Column<R>>,
+    rows: Vec<R>,
+    generation: u64,
+    sort: Option<(usize, SortOrder)>,
+    order: RefCell<Option<RowOrder>>,
+    selected: usize,
+    header_focused: bool,
+    header_selected: usize,
+}
+
+impl<R: Debug> Debug for TableView<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableView")
+            .field("columns", &self.columns)
+            .field("rows", &self.rows)
+            .field("sort", &self.sort)
+            .field("selected", &self.selected)
+            .finish()
+    }
+}
+
+impl<R> TableView<R> {
+    /// Construct a new table view with `columns` and `rows`.
+    pub fn new(columns: Vec<Column<R>>, rows: Vec<R>) -> Self {
+        Self {
+            columns,
+            rows,
+            generation: 0,
+            sort: None,
+            order: RefCell::new(None),
+            selected: 0,
+            header_focused: false,
+            header_selected: 0,
+        }
+    }
+
+    /// Returns the columns of the table.
+    pub fn columns(&self) -> &[Column<R>] {
+        &self.columns
+    }
+
+    /// Returns the rows of the table in the original order.
+    pub fn rows(&self) -> &[R] {
+        &self.rows
+    }
+
+    /// Returns the mutable rows of the table, the order is recomputed later.
+    pub fn rows_mut(&mut self) -> &mut Vec<R> {
+        self.generation += 1;
+        &mut self.rows
+    }
+
+    /// Replace all rows of the table.
+    pub fn set_rows(&mut self, rows: Vec<R>) {
+        *self.rows_mut() = rows;
+        self.selected = 0;
+    }
+
+    /// Returns true if the table no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the number of rows in the table.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the sorting column and direction.
+    pub fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sort the rows by the column at index, `None` to keep the original order.
+    ///
+    /// The current sort is kept if the column is not sortable.
+    pub fn set_sort(&mut self, sort: Option<(usize, SortOrder)>) {
+        match sort {
+            Some((i, _)) if !self.columns.get(i).is_some_and(Column::is_sortable) => {}
+            _ => self.sort = sort,
+        }
+    }
+
+    /// Sort by the column at index, or reverse the direction if already sorted by it.
+    ///
+    /// Nothing happens if the column is not sortable.
+    pub fn toggle_sort(&mut self, column: usize) {
+        let sort = match self.sort {
+            Some((i, SortOrder::Ascending)) if i == column => (column, SortOrder::Descending),
+            _ => (column, SortOrder::Ascending),
+        };
+        self.set_sort(Some(sort));
+    }
+
+    /// Returns the row indices in the display order.
+    pub fn order(&self) -> Ref<'_, [usize]> {
+        let stale = match &*self.order.borrow() {
+            Some(o) => o.sort != self.sort || o.generation != self.generation,
+            None => true,
+        };
+        if stale {
+            let mut indices: Vec<usize> = (0..self.rows.len()).collect();
+            if let Some((i, dir)) = self.sort {
+                if let Some(compare) = &self.columns[i].compare {
+                    let rows = &self.rows;
+                    // The sorting is stable, rows with equal keys keep the original order
+                    indices.sort_by(|&a, &b| match dir {
+                        SortOrder::Ascending => compare(&rows[a], &rows[b]),
+                        SortOrder::Descending => compare(&rows[b], &rows[a]),
+                    });
+                }
+            }
+            *self.order.borrow_mut() = Some(RowOrder {
+                indices,
+                sort: self.sort,
+                generation: self.generation,
+            });
+        }
+        Ref::map(self.order.borrow(), |o| {
+            o.as_ref().map(|o| o.indices.as_slice()).unwrap_or(&[])
+        })
+    }
+
+    /// Returns the `selected` row position in the display order.
+    pub fn selected(&self) -> usize {
+        self.order()
+            .iter()
+            .position(|&i| i == self.selected)
+            .unwrap_or(0)
+    }
+
+    /// Returns the `selected` row.
+    pub fn selected_row(&self) -> Option<&R> {
+        self.rows.get(self.selected)
+    }
+
+    /// Returns the `selected` row index in the original order.
+    pub fn selected_index(&self) -> Option<usize> {
+        if self.selected < self.len() {
+            Some(self.selected)
+        } else {
+            None
+        }
+    }
+
+    fn select_at(&mut self, pos: usize) {
+        let i = self.order().get(pos).copied();
+        if let Some(i) = i {
+            self.selected = i;
+        }
+    }
+
+    /// Mark `prev` row as `selected`, wrap to `last` row when current at `first` row.
+    pub fn select_prev_wrapped(&mut self) {
+        let pos = self.selected();
+        if pos == 0 {
+            self.select_at(self.len().saturating_sub(1));
+        } else {
+            self.select_at(pos - 1);
+        }
+    }
+
+    /// Mark next row as `selected`, wrap to `first` row when current at `last` row.
+    pub fn select_next_wrapped(&mut self) {
+        let pos = self.selected() + 1;
+        if pos >= self.len() {
+            self.select_at(0);
+        } else {
+            self.select_at(pos);
+        }
+    }
+
+    /// Returns true if the header row takes the input events.
+    pub fn is_header_focused(&self) -> bool {
+        self.header_focused
+    }
+
+    /// Focus the header row or the data rows.
+    pub fn set_header_focused(&mut self, focused: bool) {
+        self.header_focused = focused;
+    }
+
+    /// Returns the `selected` column index in the header row.
+    pub fn header_selected(&self) -> usize {
+        self.header_selected
+    }
+
+    /// Mark `prev` column of the header row as `selected`.
+    pub fn header_select_prev(&mut self) {
+        self.header_selected = self.header_selected.saturating_sub(1);
+    }
+
+    /// Mark next column of the header row as `selected`.
+    pub fn header_select_next(&mut self) {
+        if self.header_selected + 1 < self.columns.len() {
+            self.header_selected += 1;
+        }
+    }
+}
+
+/// A table view input controller.
+///
+/// `Key::Tab` focuses the header row, then Left/Right move between the columns and
+/// Enter sorts by the `selected` column or reverses the direction.
+#[derive(Debug)]
+pub struct TableViewInputCtrl;
+
+impl Default for TableViewInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableViewInputCtrl {
+    /// Construct a new input controller for table view.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Processing input events, returns the row index in the original order if activated.
+    pub fn process<R>(self, ctx: &Context, tv: &mut TableView<R>) -> Option<usize> {
+        let input = ctx.input();
+        if input.is_key_pressed(Key::Tab) {
+            tv.set_header_focused(!tv.is_header_focused());
+            return None;
+        }
+        if tv.is_header_focused() {
+            if input.is_key_pressed(Key::Left) {
+                tv.header_select_prev();
+            }
+            if input.is_key_pressed(Key::Right) {
+                tv.header_select_next();
+            }
+            if input.is_key_pressed(Key::Enter) {
+                tv.toggle_sort(tv.header_selected());
+            }
+            if input.is_key_pressed(Key::Down) {
+                tv.set_header_focused(false);
+            }
+            return None;
+        }
+        if input.is_key_pressed(Key::Up) {
+            tv.select_prev_wrapped();
+        }
+        if input.is_key_pressed(Key::Down) {
+            tv.select_next_wrapped();
+        }
+        if input.is_key_pressed(Key::Enter) {
+            return tv.selected_index();
+        }
+        None
+    }
+}
+
+/// A table view presenter.
+///
+/// The data rows are presented in a group named "TableView" below the header row.
+#[derive(Debug)]
+pub struct TableViewPresenter {
+    row_height: f32,
+}
+
+impl Default for TableViewPresenter {
+    fn default() -> Self {
+        Self::new(32.0)
+    }
+}
+
+impl TableViewPresenter {
+    const GROUP: &'static str = "TableView";
+
+    /// Construct a new presenter for table view.
+    pub fn new(row_height: f32) -> Self {
+        Self { row_height }
+    }
+
+    fn scroll_to_selected<R>(&self, ctx: &mut Context, tv: &TableView<R>, height: f32) {
+        let y = (tv.selected() + 1) as i32 * self.row_height as i32;
+        let offset = y - height as i32 + self.row_height as i32;
+        ctx.group_set_scroll(Self::GROUP.into(), 0, offset.max(0) as u32);
+    }
+
+    fn present_header<R>(&self, ctx: &mut Context, tv: &TableView<R>, ratios: &[f32]) {
+        let selected_bg_color = ctx.style().window().background().inverted();
+        let selected_fg_color = ctx.style().text().color.inverted();
+        let fg_color = ctx.style().text().color;
+        ctx.layout_row(LayoutFormat::Dynamic, self.row_height, ratios);
+        for (i, c) in tv.columns().iter().enumerate() {
+            let bounds = ctx.widget_bounds();
+            let highlight = tv.is_header_focused() && tv.header_selected() == i;
+            let color = if highlight {
+                selected_fg_color
+            } else {
+                fg_color
+            };
+            if let Some(canvas) = ctx.window_get_canvas_mut() {
+                if highlight {
+                    canvas.fill_rect(bounds, 0.0, selected_bg_color);
+                }
+                // Underline the header row
+                let y = bounds.y + bounds.h - 1.0;
+                canvas.stroke_line(bounds.x, y, bounds.x + bounds.w, y, 1.0, fg_color);
+                // Sort direction arrow at the right side
+                if let Some((_, dir)) = tv.sort().filter(|(col, _)| *col == i) {
+                    let size = bounds.h / 4.0;
+                    let x = bounds.x + bounds.w - size * 2.0;
+                    let y = bounds.y + (bounds.h - size) / 2.0;
+                    match dir {
+                        SortOrder::Ascending => canvas.fill_triangle(
+                            x,
+                            y + size,
+                            x + size,
+                            y + size,
+                            x + size / 2.0,
+                            y,
+                            color,
+                        ),
+                        SortOrder::Descending => {
+                            canvas.fill_triangle(x, y, x + size, y, x + size / 2.0, y + size, color)
+                        }
+                    }
+                }
+            }
+            ctx.label_colored(c.title().into(), c.align.into(), color);
+        }
+    }
+
+    /// Present the header row and the data rows on the `ctx`.
+    pub fn present<R>(self, ctx: &mut Context, tv: &TableView<R>) {
+        // Save current window states
+        let spacing = *ctx.style().window().spacing();
+        let padding = *ctx.style().window().padding();
+        let group_padding = *ctx.style().window().group_padding();
+        // Remove spacing and padding
+        ctx.style_mut().window_mut().set_spacing(vec2(0.0, 0.0));
+        ctx.style_mut().window_mut().set_padding(vec2(0.0, 0.0));
+        ctx.style_mut()
+            .window_mut()
+            .set_group_padding(vec2(0.0, 0.0));
+        let ratios: Vec<f32> = tv.columns().iter().map(|c| c.ratio).collect();
+        // Render the header row out of the group to keep it visible
+        self.present_header(ctx, tv, &ratios);
+        // Render the data rows in the remaining space
+        let height = ctx.window_get_content_region_size().y - self.row_height;
+        let height = height.max(self.row_height);
+        self.scroll_to_selected(ctx, tv, height);
+        ctx.layout_row(LayoutFormat::Dynamic, height, &[1.0]);
+        if ctx.group_begin(Self::GROUP.into(), 0) != 0 {
+            let selected_bg_color = ctx.style().window().background().inverted();
+            let selected_fg_color = ctx.style().text().color.inverted();
+            let selected = tv.selected_index();
+            for &i in tv.order().iter() {
+                let row = &tv.rows()[i];
+                if selected == Some(i) && !tv.is_header_focused() {
+                    ctx.layout_row_colored(
+                        LayoutFormat::Dynamic,
+                        self.row_height,
+                        &ratios,
+                        selected_bg_color,
+                    );
+                    for c in tv.columns() {
+                        let flags: Flags = c.align.into();
+                        ctx.label_colored(c.cell(row).into(), flags, selected_fg_color);
+                    }
+                } else {
+                    ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &ratios);
+                    for c in tv.columns() {
+                        ctx.label(c.cell(row).into(), c.align.into());
+                    }
+                }
+            }
+            ctx.group_end();
+        }
+        // Restore old window states
+        ctx.style_mut().window_mut().set_spacing(spacing);
+        ctx.style_mut().window_mut().set_padding(padding);
+        ctx.style_mut()
+            .window_mut()
+            .set_group_padding(group_padding);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(tv: &TableView<(&'static str, u32)>) -> Vec<&'static str> {
+        tv.order().iter().map(|&i| tv.rows()[i].0).collect()
+    }
+
+    #[test]
+    fn test_table_view() {
+        let columns = vec![
+            Column::new("Name", 0.6, |r: &(&str, u32)| r.0.to_string())
+                .with_sort_key(|r: &(&str, u32)| r.0),
+            Column::new("Size", 0.4, |r: &(&str, u32)| r.1.to_string())
+                .with_align(TextAlignment::Right)
+                .with_sort_key(|r: &(&str, u32)| r.1),
+            Column::new("Note", 0.0, |_: &(&str, u32)| String::new()),
+        ];
+        let mut tv = TableView::new(columns, vec![("c", 2), ("a", 1), ("d", 2), ("b", 1)]);
+        assert_eq!(names(&tv), ["c", "a", "d", "b"]);
+
+        // Stable sorting keeps the original order of equal keys.
+        tv.toggle_sort(1);
+        assert_eq!(names(&tv), ["a", "b", "c", "d"]);
+        tv.toggle_sort(1);
+        assert_eq!(tv.sort(), Some((1, SortOrder::Descending)));
+        assert_eq!(names(&tv), ["c", "d", "a", "b"]);
+        tv.toggle_sort(2);
+        assert_eq!(tv.sort(), Some((1, SortOrder::Descending)));
+        tv.set_sort(Some((3, SortOrder::Ascending)));
+        assert_eq!(tv.sort(), Some((1, SortOrder::Descending)));
+        tv.set_sort(None);
+        assert_eq!(names(&tv), ["c", "a", "d", "b"]);
+
+        // The selected row is kept when sorted.
+        tv.toggle_sort(0);
+        tv.select_prev_wrapped();
+        assert_eq!(tv.selected_row().unwrap().0, "b");
+        tv.toggle_sort(0);
+        assert_eq!(names(&tv), ["d", "c", "b", "a"]);
+        assert_eq!(tv.selected_row().unwrap().0, "b");
+        assert_eq!(tv.selected(), 2);
+        tv.select_next_wrapped();
+        tv.select_next_wrapped();
+        assert_eq!(tv.selected_row().unwrap().0, "d");
+        tv.select_prev_wrapped();
+        assert_eq!(tv.selected_row().unwrap().0, "a");
+
+        // The order is recomputed after the rows changed.
+        tv.rows_mut().push(("e", 0));
+        assert_eq!(names(&tv), ["e", "d", "c", "b", "a"]);
+        tv.set_rows(vec![]);
+        assert!(tv.order().is_empty());
+        assert_eq!(tv.selected_row(), None);
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{
+    color_rgba, rect, vec2, Color, Context, FlagsBuilder, LayoutFormat, Rect, StyleItem, Vec2,
+};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// The fade out time at the end of a toast.
+const FADE_OUT: Duration = Duration::from_millis(300);
+
+/// The severity of a toast.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ToastSeverity {
+    /// A neutral information, accented in blue.
+    Info,
+    /// A completed operation, accented in green.
+    Success,
+    /// A recoverable problem, accented in amber.
+    Warning,
+    /// A failed operation, accented in red.
+    Error,
+}
+
+impl ToastSeverity {
+    /// Returns the accent color of the severity.
+    pub fn color(self) -> Color {
+        match self {
+            ToastSeverity::Info => color_rgba(64, 128, 255, 255),
+            ToastSeverity::Success => color_rgba(64, 192, 64, 255),
+            ToastSeverity::Warning => color_rgba(255, 192, 0, 255),
+            ToastSeverity::Error => color_rgba(255, 64, 64, 255),
+        }
+    }
+}
+
+/// A transient notification.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    text: String,
+    severity: ToastSeverity,
+    duration: Duration,
+    elapsed: Duration,
+}
+
+impl Toast {
+    /// Returns the text of the toast.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the severity of the toast.
+    pub fn severity(&self) -> ToastSeverity {
+        self.severity
+    }
+
+    /// Returns the remaining time of the toast.
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+
+    /// Returns true if the toast has expired.
+    pub fn is_expired(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Returns the opacity in `0.0..=1.0`, it fades out in the last 300 ms.
+    pub fn opacity(&self) -> f32 {
+        (self.remaining().as_secs_f32() / FADE_OUT.as_secs_f32()).min(1.0)
+    }
+}
+
+/// A manager of the toasts, owns all states so the callers just push and forget.
+///
+/// At most `max_visible` toasts are shown at once, the others are queued and don't
+/// age until they are shown.
+#[derive(Debug)]
+pub struct ToastManager {
+    max_visible: usize,
+    visible: Vec<Toast>,
+    queued: VecDeque<Toast>,
+}
+
+impl Default for ToastManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToastManager {
+    /// Construct a new manager showing at most 3 toasts at once.
+    pub fn new() -> Self {
+        Self {
+            max_visible: 3,
+            visible: vec![],
+            queued: VecDeque::new(),
+        }
+    }
+
+    /// Set the maximum number of the visible toasts.
+    pub fn with_max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Queue a toast shown for `duration`.
+    pub fn push<S: Into<String>>(&mut self, text: S, severity: ToastSeverity, duration: Duration) {
+        self.queued.push_back(Toast {
+            text: text.into(),
+            severity,
+            duration,
+            elapsed: Duration::default(),
+        });
+        self.promote();
+    }
+
+    /// Advance the lifetimes of the visible toasts by `dt`, drop the expired ones
+    /// and show the queued ones.
+    pub fn advance(&mut self, dt: Duration) {
+        for t in self.visible.iter_mut() {
+            t.elapsed += dt;
+        }
+        self.visible.retain(|t| !t.is_expired());
+        self.promote();
+    }
+
+    /// Remove all visible and queued toasts.
+    pub fn dismiss_all(&mut self) {
+        self.visible.clear();
+        self.queued.clear();
+    }
+
+    /// Returns true if no visible or queued toasts.
+    pub fn is_empty(&self) -> bool {
+        self.visible.is_empty() && self.queued.is_empty()
+    }
+
+    /// Returns an iterator over the visible toasts, from the oldest.
+    pub fn visible(&self) -> std::slice::Iter<'_, Toast> {
+        self.visible.iter()
+    }
+
+    /// Returns the number of the queued toasts.
+    pub fn queued_len(&self) -> usize {
+        self.queued.len()
+    }
+
+    fn promote(&mut self) {
+        while self.visible.len() < self.max_visible {
+            match self.queued.pop_front() {
+                Some(t) => self.visible.push(t),
+                None => break,
+            }
+        }
+    }
+}
+
+/// The corner to stack the toasts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ToastCorner {
+    /// Stack down from the top left corner.
+    TopLeft,
+    /// Stack down from the top right corner.
+    TopRight,
+    /// Stack up from the bottom left corner.
+    BottomLeft,
+    /// Stack up from the bottom right corner.
+    BottomRight,
+}
+
+/// A toast presenter.
+#[derive(Debug)]
+pub struct ToastPresenter {
+    size: Vec2,
+    margin: f32,
+    corner: ToastCorner,
+}
+
+impl Default for ToastPresenter {
+    fn default() -> Self {
+        Self::new(Vec2 { x: 320.0, y: 40.0 })
+    }
+}
+
+impl ToastPresenter {
+    /// The window name of the toasts.
+    pub const WINDOW_NAME: &'static str = "Toasts";
+
+    /// Construct a new presenter for toasts of `size` stacked in the bottom right corner.
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            size,
+            margin: 8.0,
+            corner: ToastCorner::BottomRight,
+        }
+    }
+
+    /// Set the corner to stack the toasts.
+    pub fn with_corner(mut self, corner: ToastCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Set the margin around the toasts.
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Advance the toasts by the frame time `dt`, then present the visible ones in the
+    /// corner of the `area`. The oldest toast is the nearest one to the corner.
+    ///
+    /// Should be called after the other windows to keep it above them, the toasts ignore
+    /// the mouse so clicking them doesn't change the focus.
+    pub fn present(self, ctx: &mut Context, tm: &mut ToastManager, area: Rect, dt: Duration) {
+        tm.advance(dt);
+        let count = tm.visible.len();
+        if count == 0 {
+            // Release the window if no toasts
+            if !ctx.window_is_closed(Self::WINDOW_NAME.into()) {
+                ctx.window_close(Self::WINDOW_NAME.into());
+            }
+            return;
+        }
+        let step = self.size.y + self.margin;
+        let (w, h) = (
+            self.size.x + self.margin * 2.0,
+            step * count as f32 + self.margin,
+        );
+        let right = matches!(
+            self.corner,
+            ToastCorner::TopRight | ToastCorner::BottomRight
+        );
+        let bottom = matches!(
+            self.corner,
+            ToastCorner::BottomLeft | ToastCorner::BottomRight
+        );
+        let x = if right { area.x + area.w - w } else { area.x };
+        let y = if bottom { area.y + area.h - h } else { area.y };
+        // Save current window states
+        let background = ctx.style().window().fixed_background();
+        let spacing = *ctx.style().window().spacing();
+        let padding = *ctx.style().window().padding();
+        // Transparent window without spacing and padding
+        ctx.style_mut()
+            .window_mut()
+            .set_fixed_background(StyleItem::color(color_rgba(0, 0, 0, 0)));
+        ctx.style_mut().window_mut().set_spacing(vec2(0.0, 0.0));
+        ctx.style_mut().window_mut().set_padding(vec2(0.0, 0.0));
+        if ctx.begin(
+            Self::WINDOW_NAME.into(),
+            rect(x, y, w, h),
+            FlagsBuilder::panel().no_scroll_bar().no_input().into(),
+        ) {
+            let bg_color = *ctx.style().window().background();
+            let fg_color = ctx.style().text().color;
+            ctx.layout_space_begin(LayoutFormat::Static, h, count as i32);
+            for (i, t) in tm.visible().enumerate() {
+                let opacity = t.opacity();
+                // Slide out to the side of the corner while fading
+                let slide = (1.0 - opacity) * self.size.x;
+                let tx = if right {
+                    self.margin + slide
+                } else {
+                    self.margin - slide
+                };
+                let ty = if bottom {
+                    h - step * (i + 1) as f32
+                } else {
+                    self.margin + step * i as f32
+                };
+                ctx.layout_space_push(rect(tx, ty, self.size.x, self.size.y));
+                let bounds = ctx.widget_bounds();
+                let alpha = |mut c: Color| {
+                    c.a = (c.a as f32 * opacity) as u8;
+                    c
+                };
+                if let Some(canvas) = ctx.window_get_canvas_mut() {
+                    canvas.fill_rect(bounds, 4.0, alpha(bg_color));
+                    canvas.stroke_rect(bounds, 4.0, 1.0, alpha(t.severity().color()));
+                    let accent = rect(bounds.x, bounds.y, 6.0, bounds.h);
+                    canvas.fill_rect(accent, 0.0, alpha(t.severity().color()));
+                }
+                ctx.label_colored(
+                    t.text().into(),
+                    FlagsBuilder::align().centered().middle().into(),
+                    alpha(fg_color),
+                );
+            }
+            ctx.layout_space_end();
+        }
+        ctx.end();
+        // Restore old window states
+        ctx.style_mut()
+            .window_mut()
+            .set_fixed_background(background);
+        ctx.style_mut().window_mut().set_spacing(spacing);
+        ctx.style_mut().window_mut().set_padding(padding);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_manager() {
+        let ms = Duration::from_millis;
+        let mut tm = ToastManager::new().with_max_visible(2);
+        tm.push("Recording started", ToastSeverity::Info, ms(1000));
+        tm.push("Settings saved", ToastSeverity::Success, ms(3000));
+        tm.push("Disk full", ToastSeverity::Error, ms(1000));
+        assert_eq!(tm.visible().count(), 2);
+        assert_eq!(tm.queued_len(), 1);
+
+        tm.advance(ms(850));
+        let first = tm.visible().next().unwrap();
+        assert_eq!(first.remaining(), ms(150));
+        assert_eq!(first.opacity(), 0.5);
+        assert_eq!(tm.visible().nth(1).unwrap().opacity(), 1.0);
+
+        // The queued toast doesn't age until shown.
+        tm.advance(ms(150));
+        let texts: Vec<&str> = tm.visible().map(|t| t.text()).collect();
+        assert_eq!(texts, ["Settings saved", "Disk full"]);
+        assert_eq!(tm.queued_len(), 0);
+        tm.advance(ms(999));
+        assert_eq!(tm.visible().count(), 2);
+        tm.advance(ms(1));
+        let texts: Vec<&str> = tm.visible().map(|t| t.text()).collect();
+        assert_eq!(texts, ["Settings saved"]);
+
+        tm.push("Recording stopped", ToastSeverity::Warning, ms(1000));
+        tm.dismiss_all();
+        assert!(tm.is_empty());
+    }
+}
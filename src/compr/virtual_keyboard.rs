@@ -0,0 +1,409 @@
+use super::GridNav;
+use crate::{vec2, Context, FlagsBuilder, Key, LayoutFormat};
+
+/// The character layouts of a virtual keyboard.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum KeyboardLayout {
+    /// The lowercase letters with the digits row.
+    Lowercase,
+    /// The uppercase letters with the digits row.
+    Uppercase,
+    /// The digits in a phone keypad grid.
+    Digits,
+    /// The punctuations and the symbols.
+    Symbols,
+}
+
+impl KeyboardLayout {
+    /// Returns the rows of characters in this layout.
+    pub fn rows(self) -> &'static [&'static str] {
+        match self {
+            KeyboardLayout::Lowercase => &["1234567890", "qwertyuiop", "asdfghjkl-", "zxcvbnm,._"],
+            KeyboardLayout::Uppercase => &["1234567890", "QWERTYUIOP", "ASDFGHJKL-", "ZXCVBNM,._"],
+            KeyboardLayout::Digits => &["123", "456", "789", ".0-"],
+            KeyboardLayout::Symbols => &["!@#$%^&*()", "~`|\\/<>{}[", "]+=?\"';:,."],
+        }
+    }
+
+    /// Returns the layout switched to by the layout key.
+    pub fn next(self) -> Self {
+        match self {
+            KeyboardLayout::Lowercase => KeyboardLayout::Uppercase,
+            KeyboardLayout::Uppercase => KeyboardLayout::Digits,
+            KeyboardLayout::Digits => KeyboardLayout::Symbols,
+            KeyboardLayout::Symbols => KeyboardLayout::Lowercase,
+        }
+    }
+
+    /// Returns the caption of the layout.
+    pub fn caption(self) -> &'static str {
+        match self {
+            KeyboardLayout::Lowercase => "abc",
+            KeyboardLayout::Uppercase => "ABC",
+            KeyboardLayout::Digits => "123",
+            KeyboardLayout::Symbols => "#+=",
+        }
+    }
+}
+
+/// A key of the virtual keyboard.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VirtualKey {
+    /// Insert the character.
+    Char(char),
+    /// Remove the last character.
+    Backspace,
+    /// Insert a space.
+    Space,
+    /// Switch to next layout.
+    Layout,
+    /// Commit the text.
+    Ok,
+    /// Discard the text.
+    Cancel,
+}
+
+/// The dedicated keys at the bottom row of the virtual keyboard.
+const DEDICATED_KEYS: [VirtualKey; 5] = [
+    VirtualKey::Backspace,
+    VirtualKey::Space,
+    VirtualKey::Layout,
+    VirtualKey::Ok,
+    VirtualKey::Cancel,
+];
+
+/// The result of a virtual keyboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VirtualKeyboardResult {
+    /// The text was committed with the OK key.
+    Committed(String),
+    /// The text was discarded with the Cancel key.
+    Cancelled,
+}
+
+/// An on-screen keyboard for entering text without a physical keyboard.
+#[derive(Debug)]
+pub struct VirtualKeyboard {
+    layout: KeyboardLayout,
+    text: String,
+    max_length: usize,
+    nav: GridNav,
+}
+
+impl VirtualKeyboard {
+    /// Construct a new virtual keyboard with an empty text.
+    pub fn new(max_length: usize) -> Self {
+        Self::with_text("", max_length)
+    }
+
+    /// Construct a new virtual keyboard with initial `text`.
+    pub fn with_text<S: Into<String>>(text: S, max_length: usize) -> Self {
+        let mut text = text.into();
+        if let Some((i, _)) = text.char_indices().nth(max_length) {
+            text.truncate(i);
+        }
+        Self {
+            layout: KeyboardLayout::Lowercase,
+            text,
+            max_length,
+            nav: GridNav::new(),
+        }
+    }
+
+    /// Returns the entered text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the maximum number of characters of the text.
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    /// Returns the current layout.
+    pub fn layout(&self) -> KeyboardLayout {
+        self.layout
+    }
+
+    /// Switch to the `layout`.
+    pub fn set_layout(&mut self, layout: KeyboardLayout) {
+        // Stay on the dedicated keys if they were selected
+        let (row, col) = self.nav.selected();
+        let dedicated = row + 1 == self.rows();
+        self.layout = layout;
+        let row = if dedicated { self.rows() - 1 } else { row };
+        self.nav.select(row, col, &self.row_lens());
+    }
+
+    /// Returns the number of rows including the dedicated keys.
+    pub fn rows(&self) -> usize {
+        self.layout.rows().len() + 1
+    }
+
+    /// Returns the keys at `row`.
+    pub fn row_keys(&self, row: usize) -> Vec<VirtualKey> {
+        match self.layout.rows().get(row) {
+            Some(chars) => chars.chars().map(VirtualKey::Char).collect(),
+            None if row == self.layout.rows().len() => DEDICATED_KEYS.to_vec(),
+            None => vec![],
+        }
+    }
+
+    fn row_lens(&self) -> Vec<usize> {
+        self.layout
+            .rows()
+            .iter()
+            .map(|chars| chars.chars().count())
+            .chain(std::iter::once(DEDICATED_KEYS.len()))
+            .collect()
+    }
+
+    /// Returns the `selected` cell as `(row, col)`.
+    pub fn selected(&self) -> (usize, usize) {
+        self.nav.selected()
+    }
+
+    /// Returns the `selected` key.
+    pub fn selected_key(&self) -> VirtualKey {
+        let (row, col) = self.nav.selected();
+        self.row_keys(row)[col]
+    }
+
+    /// Move the selection up, wrap to the bottom row.
+    pub fn select_up(&mut self) {
+        self.nav.select_up(&self.row_lens());
+    }
+
+    /// Move the selection down, wrap to the top row.
+    pub fn select_down(&mut self) {
+        self.nav.select_down(&self.row_lens());
+    }
+
+    /// Move the selection left, wrap to the end of the row.
+    pub fn select_left(&mut self) {
+        self.nav.select_left(&self.row_lens());
+    }
+
+    /// Move the selection right, wrap to the start of the row.
+    pub fn select_right(&mut self) {
+        self.nav.select_right(&self.row_lens());
+    }
+
+    /// Appends a character if the text is not full.
+    pub fn push(&mut self, c: char) {
+        if self.text.chars().count() < self.max_length {
+            self.text.push(c);
+        }
+    }
+
+    /// Removes the last character.
+    pub fn backspace(&mut self) {
+        self.text.pop();
+    }
+
+    /// Press the `selected` key, returns the result if the OK or Cancel pressed.
+    pub fn press(&mut self) -> Option<VirtualKeyboardResult> {
+        match self.selected_key() {
+            VirtualKey::Char(c) => self.push(c),
+            VirtualKey::Backspace => self.backspace(),
+            VirtualKey::Space => self.push(' '),
+            VirtualKey::Layout => self.set_layout(self.layout.next()),
+            VirtualKey::Ok => return Some(VirtualKeyboardResult::Committed(self.text.clone())),
+            VirtualKey::Cancel => return Some(VirtualKeyboardResult::Cancelled),
+        }
+        None
+    }
+}
+
+/// A virtual keyboard input controller.
+#[derive(Debug)]
+pub struct VirtualKeyboardInputCtrl;
+
+impl Default for VirtualKeyboardInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VirtualKeyboardInputCtrl {
+    /// Construct a new input controller for virtual keyboard.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Processing input events, returns the result if the OK or Cancel pressed.
+    pub fn process(self, ctx: &Context, vk: &mut VirtualKeyboard) -> Option<VirtualKeyboardResult> {
+        let input = ctx.input();
+        if input.is_key_pressed(Key::Up) {
+            vk.select_up();
+        }
+        if input.is_key_pressed(Key::Down) {
+            vk.select_down();
+        }
+        if input.is_key_pressed(Key::Left) {
+            vk.select_left();
+        }
+        if input.is_key_pressed(Key::Right) {
+            vk.select_right();
+        }
+        if input.is_key_pressed(Key::Backspace) {
+            vk.backspace();
+        }
+        if input.is_key_pressed(Key::Enter) {
+            return vk.press();
+        }
+        None
+    }
+}
+
+/// A virtual keyboard presenter.
+#[derive(Debug)]
+pub struct VirtualKeyboardPresenter {
+    row_height: f32,
+}
+
+impl Default for VirtualKeyboardPresenter {
+    fn default() -> Self {
+        Self::new(32.0)
+    }
+}
+
+impl VirtualKeyboardPresenter {
+    /// Construct a new presenter for virtual keyboard.
+    pub fn new(row_height: f32) -> Self {
+        Self { row_height }
+    }
+
+    fn caption(vk: &VirtualKeyboard, key: VirtualKey) -> String {
+        match key {
+            VirtualKey::Char(c) => c.to_string(),
+            VirtualKey::Backspace => "<-".into(),
+            VirtualKey::Space => "Space".into(),
+            VirtualKey::Layout => vk.layout().next().caption().into(),
+            VirtualKey::Ok => "OK".into(),
+            VirtualKey::Cancel => "Cancel".into(),
+        }
+    }
+
+    /// Present the text line and the keys on the `ctx`.
+    pub fn present(self, ctx: &mut Context, vk: &VirtualKeyboard) {
+        // Save current window states
+        let spacing = *ctx.style().window().spacing();
+        let padding = *ctx.style().window().padding();
+        // Remove spacing and padding
+        ctx.style_mut().window_mut().set_spacing(vec2(0.0, 0.0));
+        ctx.style_mut().window_mut().set_padding(vec2(0.0, 0.0));
+        let selected_bg_color = ctx.style().window().background().inverted();
+        let selected_fg_color = ctx.style().text().color.inverted();
+        let border_color = ctx.style().text().color;
+        // Render the text line with a cursor
+        ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &[1.0]);
+        let bounds = ctx.widget_bounds();
+        if let Some(canvas) = ctx.window_get_canvas_mut() {
+            canvas.stroke_rect(bounds, 0.0, 1.0, border_color);
+        }
+        ctx.label(
+            format!(" {}_", vk.text()).into(),
+            FlagsBuilder::align().left().middle().into(),
+        );
+        // Render each row of keys
+        let (sel_row, sel_col) = vk.selected();
+        for row in 0..vk.rows() {
+            let keys = vk.row_keys(row);
+            let ratios = vec![1.0 / keys.len() as f32; keys.len()];
+            ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &ratios);
+            for (col, key) in keys.into_iter().enumerate() {
+                let bounds = ctx.widget_bounds();
+                let caption = Self::caption(vk, key);
+                if row == sel_row && col == sel_col {
+                    if let Some(canvas) = ctx.window_get_canvas_mut() {
+                        canvas.fill_rect(bounds, 0.0, selected_bg_color);
+                    }
+                    ctx.label_colored(
+                        caption.into(),
+                        FlagsBuilder::align().centered().middle().into(),
+                        selected_fg_color,
+                    );
+                } else {
+                    if let Some(canvas) = ctx.window_get_canvas_mut() {
+                        canvas.stroke_rect(bounds, 0.0, 1.0, border_color);
+                    }
+                    ctx.label(
+                        caption.into(),
+                        FlagsBuilder::align().centered().middle().into(),
+                    );
+                }
+            }
+        }
+        // Restore old window states
+        ctx.style_mut().window_mut().set_spacing(spacing);
+        ctx.style_mut().window_mut().set_padding(padding);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_keyboard() {
+        let mut vk = VirtualKeyboard::with_text("hello", 6);
+        assert_eq!(vk.selected_key(), VirtualKey::Char('1'));
+
+        // Wrap up to the dedicated keys and back.
+        vk.select_up();
+        assert_eq!(vk.selected_key(), VirtualKey::Backspace);
+        assert_eq!(vk.press(), None);
+        assert_eq!(vk.text(), "hell");
+        vk.select_down();
+        assert_eq!(vk.selected(), (0, 0));
+
+        // Type "oo", the third press hits the max length.
+        vk.select_down();
+        for _ in 0..8 {
+            vk.select_right();
+        }
+        assert_eq!(vk.selected_key(), VirtualKey::Char('o'));
+        vk.press();
+        vk.press();
+        vk.press();
+        assert_eq!(vk.text(), "helloo");
+
+        // Switch the layout from the bottom row, the column is kept in range.
+        vk.backspace();
+        vk.select_up();
+        vk.select_up();
+        assert_eq!(vk.selected(), (4, 4));
+        vk.select_left();
+        vk.select_left();
+        assert_eq!(vk.selected_key(), VirtualKey::Layout);
+        vk.press();
+        vk.press();
+        assert_eq!(vk.layout(), KeyboardLayout::Digits);
+        vk.select_up();
+        assert_eq!(vk.selected_key(), VirtualKey::Char('0'));
+        vk.select_right();
+        vk.press();
+        vk.select_down();
+        assert_eq!(vk.selected_key(), VirtualKey::Ok);
+        assert_eq!(
+            vk.press(),
+            Some(VirtualKeyboardResult::Committed("hello-".into()))
+        );
+        vk.select_right();
+        assert_eq!(vk.press(), Some(VirtualKeyboardResult::Cancelled));
+
+        // Cycle all layouts with the layout key.
+        let mut vk = VirtualKeyboard::new(8);
+        vk.select_up();
+        vk.select_right();
+        vk.select_right();
+        for _ in 0..4 {
+            assert_eq!(vk.selected_key(), VirtualKey::Layout);
+            vk.press();
+        }
+        assert_eq!(vk.layout(), KeyboardLayout::Lowercase);
+
+        assert_eq!(VirtualKeyboard::with_text("truncated", 5).text(), "trunc");
+    }
+}
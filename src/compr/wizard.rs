@@ -0,0 +1,383 @@
+use super::{PropertySheet, TabContent};
+use crate::{color_rgba, Context, FlagsBuilder, Key, LayoutFormat};
+use std::fmt::Debug;
+
+type Validator = Box<dyn Fn(&PropertySheet) -> Result<(), String>>;
+type FinishedCallback = Box<dyn FnMut(&[&PropertySheet])>;
+
+/// A page of the wizard.
+pub struct WizardPage {
+    name: String,
+    content: TabContent,
+    validator: Option<Validator>,
+}
+
+impl Debug for WizardPage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WizardPage")
+            .field("name", &self.name)
+            .field("content", &self.content)
+            .field("validator", &self.validator.is_some())
+            .finish()
+    }
+}
+
+impl WizardPage {
+    /// Returns the name of the page.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the content of the page.
+    pub fn content(&self) -> &TabContent {
+        &self.content
+    }
+
+    /// Returns the mutable content of the page.
+    pub fn content_mut(&mut self) -> &mut TabContent {
+        &mut self.content
+    }
+
+    /// Set the validator blocking the Next until it returns `Ok`.
+    ///
+    /// Only the pages of `TabContent::PropertySheet` are validated.
+    pub fn set_validator<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&PropertySheet) -> Result<(), String> + 'static,
+    {
+        self.validator = Some(Box::new(f));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match (&self.content, &self.validator) {
+            (TabContent::PropertySheet(ps), Some(f)) => f(ps),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// An ordered pages of a multi-step flow.
+#[derive(Default)]
+pub struct Wizard {
+    pages: Vec<WizardPage>,
+    current: usize,
+    error: Option<String>,
+    finished: bool,
+    on_finished: Option<FinishedCallback>,
+}
+
+impl Debug for Wizard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Wizard")
+            .field("pages", &self.pages)
+            .field("current", &self.current)
+            .field("error", &self.error)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl Wizard {
+    /// Construct a new empty wizard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a page to the back of the wizard, returns it to set the validator.
+    pub fn append<S: Into<String>>(&mut self, name: S, content: TabContent) -> &mut WizardPage {
+        self.pages.push(WizardPage {
+            name: name.into(),
+            content,
+            validator: None,
+        });
+        self.pages.last_mut().unwrap()
+    }
+
+    /// Set the callback called with the sheets of all pages when finished.
+    pub fn on_finished<F>(&mut self, f: F)
+    where
+        F: FnMut(&[&PropertySheet]) + 'static,
+    {
+        self.on_finished = Some(Box::new(f));
+    }
+
+    /// Returns true if the wizard no pages.
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    /// Returns the number of pages in the wizard.
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns the page reference at index in the wizard.
+    pub fn get(&self, index: usize) -> Option<&WizardPage> {
+        self.pages.get(index)
+    }
+
+    /// Returns an iterator over the pages.
+    pub fn iter(&self) -> std::slice::Iter<'_, WizardPage> {
+        self.pages.iter()
+    }
+
+    /// Returns the `current` page index.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the `current` page.
+    pub fn current_page(&self) -> Option<&WizardPage> {
+        self.pages.get(self.current)
+    }
+
+    /// Returns the mutable `current` page.
+    pub fn current_page_mut(&mut self) -> Option<&mut WizardPage> {
+        self.pages.get_mut(self.current)
+    }
+
+    /// Returns true if the `current` page is the last one.
+    pub fn is_last(&self) -> bool {
+        self.current + 1 >= self.len()
+    }
+
+    /// Returns the progress of the wizard, such as "Step 2 of 5".
+    pub fn progress_text(&self) -> String {
+        format!("Step {} of {}", self.current + 1, self.len())
+    }
+
+    /// Returns the error of the last failed validation.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns true if the last page was completed.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Validate the `current` page and move to the next page, finish the wizard
+    /// at the last page. Returns false if blocked by the validator.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> bool {
+        if self.finished {
+            return false;
+        }
+        if let Some(Err(e)) = self.current_page().map(|p| p.validate()) {
+            self.error = Some(e);
+            return false;
+        }
+        self.error = None;
+        if self.is_last() {
+            self.finished = true;
+            let sheets: Vec<&PropertySheet> = self
+                .pages
+                .iter()
+                .filter_map(|p| match &p.content {
+                    TabContent::PropertySheet(ps) => Some(ps),
+                    _ => None,
+                })
+                .collect();
+            if let Some(f) = self.on_finished.as_mut() {
+                f(&sheets);
+            }
+        } else {
+            self.current += 1;
+        }
+        true
+    }
+
+    /// Move to the previous page without validation.
+    pub fn back(&mut self) {
+        if !self.finished && self.current > 0 {
+            self.current -= 1;
+            self.error = None;
+        }
+    }
+
+    /// Start the wizard over from the first page.
+    pub fn restart(&mut self) {
+        self.current = 0;
+        self.error = None;
+        self.finished = false;
+    }
+}
+
+/// A wizard input controller.
+#[derive(Debug)]
+pub struct WizardInputCtrl {
+    back: Key,
+    next: Key,
+}
+
+impl Default for WizardInputCtrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WizardInputCtrl {
+    /// Construct a new input controller navigating with `Key::ScrollUp` and `Key::ScrollDown`.
+    pub fn new() -> Self {
+        Self::with_keys(Key::ScrollUp, Key::ScrollDown)
+    }
+
+    /// Construct a new input controller navigating with the `back` and `next` keys.
+    pub fn with_keys(back: Key, next: Key) -> Self {
+        Self { back, next }
+    }
+
+    /// Processing input events, the events not for navigation are forwarded to the `current` page.
+    pub fn process(self, ctx: &Context, wz: &mut Wizard) {
+        if wz.is_finished() {
+            return;
+        }
+        let editing = match wz.current_page().map(|p| &p.content) {
+            Some(TabContent::PropertySheet(ps)) => ps.editor().is_some(),
+            _ => false,
+        };
+        let input = ctx.input();
+        if !editing {
+            if input.is_key_pressed(self.back) {
+                wz.back();
+                return;
+            }
+            if input.is_key_pressed(self.next) {
+                wz.next();
+                return;
+            }
+        }
+        if let Some(p) = wz.current_page_mut() {
+            p.content.process(ctx);
+        }
+    }
+}
+
+/// A wizard presenter.
+#[derive(Debug)]
+pub struct WizardPresenter {
+    row_height: f32,
+}
+
+impl Default for WizardPresenter {
+    fn default() -> Self {
+        Self::new(32.0)
+    }
+}
+
+impl WizardPresenter {
+    /// Construct a new presenter for wizard.
+    pub fn new(row_height: f32) -> Self {
+        Self { row_height }
+    }
+
+    fn present_button(&self, ctx: &mut Context, text: &str, enabled: bool) {
+        let bounds = ctx.widget_bounds();
+        let mut color = ctx.style().text().color;
+        if !enabled {
+            color.a /= 2;
+        }
+        if let Some(canvas) = ctx.window_get_canvas_mut() {
+            canvas.stroke_rect(bounds, 0.0, 1.0, color);
+        }
+        ctx.label_colored(
+            text.into(),
+            FlagsBuilder::align().centered().middle().into(),
+            color,
+        );
+    }
+
+    /// Present the header, the `current` page and the footer on the `ctx`.
+    pub fn present(self, ctx: &mut Context, wz: &Wizard) {
+        let page = match wz.current_page() {
+            Some(page) => page,
+            None => return,
+        };
+        // Header
+        ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &[0.6, 0.4]);
+        ctx.label(
+            page.name().into(),
+            FlagsBuilder::align().left().middle().into(),
+        );
+        ctx.label(
+            wz.progress_text().into(),
+            FlagsBuilder::align().right().middle().into(),
+        );
+        // Content
+        page.content.present(ctx, self.row_height, self.row_height);
+        // Footer
+        if let Some(e) = wz.error() {
+            ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &[1.0]);
+            ctx.label_colored(
+                e.into(),
+                FlagsBuilder::align().left().middle().into(),
+                color_rgba(255, 64, 64, 255),
+            );
+        }
+        ctx.layout_row(LayoutFormat::Dynamic, self.row_height, &[0.3, 0.4, 0.3]);
+        self.present_button(ctx, "< Back", wz.current() > 0);
+        ctx.spacing(1);
+        let next = if wz.is_last() { "Finish" } else { "Next >" };
+        self.present_button(ctx, next, !wz.is_finished());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_wizard() {
+        let mut wz = Wizard::new();
+        let mut language = PropertySheet::new();
+        language.combo_box_i32("Language", &["English", "Deutsch"], 0);
+        wz.append("Language", TabContent::PropertySheet(language));
+        let mut network = PropertySheet::new();
+        network.text_box("SSID", 32, "");
+        wz.append("Network", TabContent::PropertySheet(network))
+            .set_validator(|ps| match ps.get_value_string_of("SSID") {
+                Some(v) if !v.is_empty() => Ok(()),
+                _ => Err("SSID is required".into()),
+            });
+        wz.append("Done", TabContent::custom(|_ctx: &mut Context| {}));
+
+        let finished = Rc::new(RefCell::new(vec![]));
+        let cloned = Rc::clone(&finished);
+        wz.on_finished(move |sheets| {
+            for ps in sheets {
+                cloned.borrow_mut().push(ps.len());
+            }
+        });
+
+        assert_eq!(wz.progress_text(), "Step 1 of 3");
+        wz.back();
+        assert_eq!(wz.current(), 0);
+        assert!(wz.next());
+        assert!(!wz.next());
+        assert_eq!(wz.error(), Some("SSID is required"));
+        assert_eq!(wz.current(), 1);
+
+        if let TabContent::PropertySheet(ps) = wz.current_page_mut().unwrap().content_mut() {
+            ps.find("SSID")
+                .unwrap()
+                .as_property_string()
+                .unwrap()
+                .set_value("nuki");
+        }
+        assert!(wz.next());
+        assert_eq!(wz.error(), None);
+        assert_eq!(wz.progress_text(), "Step 3 of 3");
+        assert!(finished.borrow().is_empty());
+        assert!(wz.next());
+        assert!(wz.is_finished());
+        assert_eq!(*finished.borrow(), [1, 1]);
+        assert!(!wz.next());
+
+        wz.restart();
+        assert_eq!(wz.current(), 0);
+        assert!(!wz.is_finished());
+    }
+}
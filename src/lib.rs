@@ -337,7 +337,7 @@ from_into_enum!(CommandType, nk_command_type);
 // ==========================================================================================================
 
 #[repr(C)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SymbolType {
     None = nk_symbol_type_NK_SYMBOL_NONE as isize,
     X = nk_symbol_type_NK_SYMBOL_X as isize,
@@ -572,7 +572,7 @@ from_into_enum!(TextAlignment, nk_text_alignment);
 // ==========================================================================================================
 
 #[repr(C)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Key {
     None = nk_keys_NK_KEY_NONE as isize,
     Shift = nk_keys_NK_KEY_SHIFT as isize,
@@ -4895,6 +4895,26 @@ impl Context {
         }
     }
 
+    pub fn group_get_scroll(&mut self, id: String) -> (u32, u32) {
+        let mut x: u32 = 0;
+        let mut y: u32 = 0;
+        unsafe {
+            nk_group_get_scroll(
+                &mut self.internal as *mut nk_context,
+                id.as_ptr(),
+                &mut x,
+                &mut y,
+            )
+        }
+        (x, y)
+    }
+
+    pub fn group_set_scroll(&mut self, id: String, x: u32, y: u32) {
+        unsafe {
+            nk_group_set_scroll(&mut self.internal as *mut nk_context, id.as_ptr(), x, y);
+        }
+    }
+
     pub fn tree_push_hashed(
         &mut self,
         ty: TreeType,
@@ -7693,7 +7713,7 @@ impl CommandBuffer {
             nk_stroke_polyline(
                 &mut self.internal,
                 &mut points[0] as *mut f32,
-                points.len() as ::std::os::raw::c_int,
+                (points.len() / 2) as ::std::os::raw::c_int,
                 line_thickness,
                 color,
             );
@@ -7705,7 +7725,7 @@ impl CommandBuffer {
             nk_stroke_polygon(
                 &mut self.internal,
                 &mut points[0] as *mut f32,
-                points.len() as ::std::os::raw::c_int,
+                (points.len() / 2) as ::std::os::raw::c_int,
                 line_thickness,
                 color,
             );
@@ -7771,7 +7791,7 @@ impl CommandBuffer {
             nk_fill_polygon(
                 &mut self.internal,
                 &mut points[0] as *mut f32,
-                points.len() as ::std::os::raw::c_int,
+                (points.len() / 2) as ::std::os::raw::c_int,
                 color,
             );
         }
@@ -8146,6 +8166,24 @@ impl UserFont {
     pub unsafe fn userdata_id(&self) -> Handle {
         Handle::from_id(self.internal.userdata.id)
     }
+
+    pub fn height(&self) -> f32 {
+        self.internal.height
+    }
+
+    pub fn text_width(&self, text: &str) -> f32 {
+        match self.internal.width {
+            Some(f) => unsafe {
+                f(
+                    self.internal.userdata,
+                    self.internal.height,
+                    text.as_ptr() as *const ::std::os::raw::c_char,
+                    text.len() as ::std::os::raw::c_int,
+                )
+            },
+            None => 0.0,
+        }
+    }
 }
 
 // =============================================================================================
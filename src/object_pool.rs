@@ -2,7 +2,33 @@
 //!
 //! The goal of an object pool is to reuse expensive to allocate objects or frequently allocated objects.
 //!
+//! With the `derive` feature, `#[derive(PoolObject)]` implements the [`PoolObjectTypeId`]
+//! and the `base()`/`base_mut()` accessors of a pooled object:
+//!
+//! ```ignore
+//! use nuki::object_pool::*;
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Default, PoolObject)]
+//! #[pool(type_id = 3)]
+//! struct ObjectFoo {
+//!     base: PoolObjectBase,
+//!     val: u64,
+//! }
+//! ```
+//!
+use std::collections::BTreeMap;
 use std::iter::Iterator;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[cfg(debug_assertions)]
+use std::sync::Mutex;
+
+#[cfg(feature = "derive")]
+pub use nuki_derive::PoolObject;
+
+/// The reserved type of the pooled array objects, see [`ForwardPool::alloc_slice`].
+pub const POOL_OBJECT_TYPE_ARRAY: usize = u16::MAX as usize;
 
 /// Forward Only Object Pool.
 ///
@@ -47,6 +73,15 @@ use std::iter::Iterator;
 pub struct ForwardPool {
     buf: Vec<u8>,
     offset: usize,
+    peak_used: usize,
+    allocation_count: usize,
+    failed_allocation_count: usize,
+    type_stats: Option<BTreeMap<usize, PoolTypeStats>>,
+    corrupted: AtomicBool,
+    strict: bool,
+    /// The start offsets of the allocated objects, to check the headers in debug builds.
+    #[cfg(debug_assertions)]
+    starts: Vec<usize>,
 }
 
 impl ForwardPool {
@@ -55,6 +90,14 @@ impl ForwardPool {
         Self {
             buf: Vec::with_capacity(capacity),
             offset: 0,
+            peak_used: 0,
+            allocation_count: 0,
+            failed_allocation_count: 0,
+            type_stats: None,
+            corrupted: AtomicBool::new(false),
+            strict: cfg!(debug_assertions),
+            #[cfg(debug_assertions)]
+            starts: vec![],
         }
     }
 
@@ -80,28 +123,250 @@ impl ForwardPool {
         self.offset
     }
 
+    /// Returns `true` if a corrupted object header was found while iterating.
+    ///
+    /// The iteration terminates at the corrupted header, the flag is reset by [`clear`].
+    ///
+    /// [`clear`]: ForwardPool::clear
+    pub fn is_corrupted(&self) -> bool {
+        self.corrupted.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if a corrupted object header panics instead of terminating the iteration.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Panic on a corrupted object header or not, enabled by default in debug builds.
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Returns `true` if the elements of the array at `array` are in the object of `size`.
+    ///
+    /// The fields are read one by one only if the object is large enough to hold them.
+    fn is_array_intact(array: *const PoolArrayBase, size: usize) -> bool {
+        let header_size = std::mem::size_of::<PoolArrayBase>();
+        size >= header_size && {
+            let field = |f: *const u16| unsafe { f.read() as usize };
+            let data_offset = field(unsafe { std::ptr::addr_of!((*array).data_offset) });
+            data_offset >= header_size && {
+                let len = field(unsafe { std::ptr::addr_of!((*array).len) });
+                let elem_size = field(unsafe { std::ptr::addr_of!((*array).elem_size) });
+                data_offset + len * elem_size <= size
+            }
+        }
+    }
+
+    /// Returns the size of the object header at `cursor`, or `None` if the header is
+    /// corrupted, e.g. the size of the object is zero or runs past the used bytes.
+    ///
+    /// In debug builds, the header must also start at an offset where an object allocated.
+    fn checked_object_size(&self, cursor: usize) -> Option<usize> {
+        let header_size = std::mem::size_of::<PoolObjectBase>();
+        let left = self.offset - cursor;
+        #[cfg(debug_assertions)]
+        let allocated = self.starts.binary_search(&cursor).is_ok();
+        #[cfg(not(debug_assertions))]
+        let allocated = true;
+        let intact = allocated && left >= header_size && {
+            let base = unsafe { &*(self.buf.as_ptr().add(cursor) as *const PoolObjectBase) };
+            let size = base.pool_object_size();
+            size >= header_size
+                && size <= left
+                && size % std::mem::align_of::<PoolObjectBase>() == 0
+                && (base.pool_object_type() != POOL_OBJECT_TYPE_ARRAY
+                    || Self::is_array_intact(base as *const _ as *const PoolArrayBase, size))
+        };
+        if self.strict {
+            assert!(intact, "corrupted object header at offset {}", cursor);
+        }
+        if intact {
+            let base = unsafe { &*(self.buf.as_ptr().add(cursor) as *const PoolObjectBase) };
+            Some(base.pool_object_size())
+        } else {
+            self.corrupted.store(true, Ordering::Relaxed);
+            None
+        }
+    }
+
+    /// Return the high-water mark of used bytes since created or [`reset_stats`].
+    ///
+    /// [`reset_stats`]: ForwardPool::reset_stats
+    pub fn peak_used(&self) -> usize {
+        self.peak_used
+    }
+
+    /// Return the number of succeeded allocations.
+    pub fn allocation_count(&self) -> usize {
+        self.allocation_count
+    }
+
+    /// Return the number of failed allocations.
+    pub fn failed_allocation_count(&self) -> usize {
+        self.failed_allocation_count
+    }
+
+    /// Returns `true` if the per-type statistics collecting enabled.
+    pub fn is_type_stats_enabled(&self) -> bool {
+        self.type_stats.is_some()
+    }
+
+    /// Enable or disable the per-type statistics collecting.
+    ///
+    /// The collected statistics will be discarded when disabled.
+    pub fn set_type_stats_enabled(&mut self, enabled: bool) {
+        if enabled != self.is_type_stats_enabled() {
+            self.type_stats = if enabled { Some(BTreeMap::new()) } else { None };
+        }
+    }
+
+    /// Reset all statistics of the pool.
+    ///
+    /// The statistics are not reset by [`clear`], so the peak of a whole session
+    /// survives the per-frame clears.
+    ///
+    /// [`clear`]: ForwardPool::clear
+    pub fn reset_stats(&mut self) {
+        self.peak_used = self.offset;
+        self.allocation_count = 0;
+        self.failed_allocation_count = 0;
+        if let Some(stats) = self.type_stats.as_mut() {
+            stats.clear();
+        }
+    }
+
+    /// Returns a report of the pool usage statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nuki::object_pool::ForwardPool;
+    ///
+    /// let pool = ForwardPool::with_capacity(1024);
+    /// log::trace!("{:#?}", pool.report());
+    /// ```
+    pub fn report(&self) -> PoolReport {
+        PoolReport {
+            capacity: self.buf.capacity(),
+            used: self.offset,
+            peak_used: self.peak_used,
+            allocation_count: self.allocation_count,
+            failed_allocation_count: self.failed_allocation_count,
+            type_stats: self.type_stats.clone().unwrap_or_default(),
+        }
+    }
+
+    fn record_alloc(&mut self, type_: usize, size: usize) {
+        self.allocation_count += 1;
+        self.peak_used = self.peak_used.max(self.offset);
+        if let Some(stats) = self.type_stats.as_mut() {
+            let s = stats.entry(type_).or_default();
+            s.count += 1;
+            s.bytes += size;
+        }
+    }
+
+    fn record_failure(&mut self) {
+        self.failed_allocation_count += 1;
+    }
+
     /// Allocate an object and return the mutable reference.
+    ///
+    /// Returns `None` if the pool does not have enough space or the type of the object
+    /// is the reserved [`POOL_OBJECT_TYPE_ARRAY`].
     pub fn alloc<T: PoolObjectTypeId + Default>(&mut self, def_val: Option<T>) -> Option<&mut T> {
+        let size = std::mem::size_of::<T>();
+        let val = def_val.unwrap_or_default();
+        let type_ = val.pool_object_type_id();
+        if self.space() < size || type_ as u16 as usize == POOL_OBJECT_TYPE_ARRAY {
+            self.record_failure();
+            return None;
+        }
         unsafe {
-            if self.buf.capacity() - self.offset < std::mem::size_of::<T>() {
-                None
-            } else {
-                let size = std::mem::size_of::<T>();
-                let ptr = self.buf.as_mut_ptr().add(self.offset);
-                let base = &mut *(ptr as *mut PoolObjectBase);
-                let obj = &mut *(ptr as *mut T);
-                *obj = def_val.unwrap_or_default();
-                base.set_pool_object_type(obj.pool_object_type_id());
-                base.set_pool_object_size(size);
-                self.offset += size;
-                Some(obj)
+            let ptr = self.buf.as_mut_ptr().add(self.offset);
+            std::ptr::write(ptr as *mut T, val);
+            let base = &mut *(ptr as *mut PoolObjectBase);
+            let obj = &mut *(ptr as *mut T);
+            base.set_pool_object_type(type_);
+            base.set_pool_object_size(size);
+            #[cfg(debug_assertions)]
+            self.starts.push(self.offset);
+            self.offset += size;
+            self.record_alloc(base.pool_object_type(), size);
+            Some(obj)
+        }
+    }
+
+    /// Allocate an array of `n` objects and return the mutable slice.
+    ///
+    /// Only one [`PoolArrayBase`] header is reserved for the whole array, each element is
+    /// constructed by `init` with it's index. The size of the array is padded to the
+    /// alignment of `T` or `u64`, whichever is larger. The array is reported as a single object
+    /// by the iterators, use [`PoolObject::as_pool_array`] to access the element count.
+    ///
+    /// Returns `None` if the pool does not have enough space, `T` is zero-sized, or the size
+    /// of `T`, `n` or the total size of the array exceeds the size limit of the object header
+    /// (`u16::MAX`).
+    pub fn alloc_slice<T: PoolObjectTypeId>(
+        &mut self,
+        n: usize,
+        init: impl Fn(usize) -> T,
+    ) -> Option<&mut [T]> {
+        let header_size = std::mem::size_of::<PoolArrayBase>();
+        let elem_size = std::mem::size_of::<T>();
+        // The element size and count must fit in the header, a zero size breaks the iteration.
+        if elem_size == 0 || elem_size > u16::MAX as usize || n > u16::MAX as usize {
+            self.record_failure();
+            return None;
+        }
+        // Keep the following objects aligned as well as the elements.
+        let align = std::mem::align_of::<T>().max(std::mem::align_of::<u64>());
+        let elem_align = std::mem::align_of::<T>();
+        let addr = self.buf.as_ptr() as usize + self.offset + header_size;
+        let data_offset = header_size + (elem_align - addr % elem_align) % elem_align;
+        let size = match elem_size
+            .checked_mul(n)
+            .and_then(|v| v.checked_add(data_offset))
+            .map(|v| v.div_ceil(align) * align)
+        {
+            Some(v) if v <= u16::MAX as usize && v <= self.space() => v,
+            _ => {
+                self.record_failure();
+                return None;
+            }
+        };
+        unsafe {
+            let ptr = self.buf.as_mut_ptr().add(self.offset);
+            let header = &mut *(ptr as *mut PoolArrayBase);
+            header.base.set_pool_object_type(POOL_OBJECT_TYPE_ARRAY);
+            header.base.set_pool_object_size(size);
+            header.elem_type = 0;
+            header.elem_size = elem_size as u16;
+            header.len = n as u16;
+            header.data_offset = data_offset as u16;
+            let data = ptr.add(data_offset) as *mut T;
+            for i in 0..n {
+                std::ptr::write(data.add(i), init(i));
+            }
+            let slice = std::slice::from_raw_parts_mut(data, n);
+            if let Some(first) = slice.first() {
+                header.elem_type = first.pool_object_type_id() as u16;
             }
+            #[cfg(debug_assertions)]
+            self.starts.push(self.offset);
+            self.offset += size;
+            self.record_alloc(POOL_OBJECT_TYPE_ARRAY, size);
+            Some(slice)
         }
     }
 
     /// Clear all objects in the pool.
     pub fn clear(&mut self) {
         self.offset = 0;
+        *self.corrupted.get_mut() = false;
+        #[cfg(debug_assertions)]
+        self.starts.clear();
     }
 
     /// Returns an iterator over the objects.
@@ -115,6 +380,445 @@ impl ForwardPool {
     }
 }
 
+/// Thread-safe Forward Only Object Pool.
+///
+/// The objects can be allocated from several threads at the same time, the allocation
+/// is a lock-free bump of the offset. Iterating or clearing the objects requires exclusive
+/// access to the pool, use [`freeze`] to get a [`ForwardPool`] snapshot for reading.
+/// The usage statistics of the allocations are collected when frozen.
+///
+/// # Thread Safety
+///
+/// Each allocation reserves a distinct region of the buffer by an atomic update of the
+/// offset, so the returned `&mut T` never aliases with another allocation. The regions
+/// are only reused after [`clear`], which takes `&mut self` and therefore can not run
+/// while any returned reference is still alive.
+///
+/// # Examples
+///
+/// ```
+/// use nuki::object_pool::*;
+///
+/// #[repr(C)]
+/// #[derive(Debug, Default)]
+/// struct ObjectFoo {
+///     base: PoolObjectBase,
+///     val: u64,
+/// }
+///
+/// impl PoolObjectTypeId for ObjectFoo {
+///     fn pool_object_type_id(&self) -> usize {
+///         1
+///     }
+/// }
+///
+/// let mut pool = SyncForwardPool::with_capacity(1024);
+/// std::thread::scope(|s| {
+///     for i in 0..4 {
+///         let pool = &pool;
+///         s.spawn(move || {
+///             pool.alloc(Some(ObjectFoo { val: i, ..Default::default() }));
+///         });
+///     }
+/// });
+/// assert_eq!(pool.freeze().iter().count(), 4);
+/// ```
+///
+/// [`freeze`]: SyncForwardPool::freeze
+/// [`clear`]: SyncForwardPool::clear
+#[derive(Debug)]
+pub struct SyncForwardPool {
+    pool: ForwardPool,
+    ptr: *mut u8,
+    offset: AtomicUsize,
+    failed_allocation_count: AtomicUsize,
+    /// The start offsets of the objects allocated since the last sync, in debug builds.
+    #[cfg(debug_assertions)]
+    starts: Mutex<Vec<usize>>,
+}
+
+unsafe impl Send for SyncForwardPool {}
+unsafe impl Sync for SyncForwardPool {}
+
+impl SyncForwardPool {
+    /// Create the pool with capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut pool = ForwardPool::with_capacity(capacity);
+        let ptr = pool.buf.as_mut_ptr();
+        Self {
+            pool,
+            ptr,
+            offset: AtomicUsize::new(0),
+            failed_allocation_count: AtomicUsize::new(0),
+            #[cfg(debug_assertions)]
+            starts: Mutex::new(vec![]),
+        }
+    }
+
+    /// Enable or disable the per-type statistics collecting, see
+    /// [`ForwardPool::set_type_stats_enabled`].
+    pub fn set_type_stats_enabled(&mut self, enabled: bool) {
+        self.pool.set_type_stats_enabled(enabled);
+    }
+
+    /// Return available bytes.
+    pub fn space(&self) -> usize {
+        self.pool.buf.capacity() - self.used()
+    }
+
+    /// Return used bytes.
+    pub fn used(&self) -> usize {
+        self.offset.load(Ordering::Acquire)
+    }
+
+    /// Reserve `size` bytes and returns the offset of the region.
+    fn reserve(&self, size: usize) -> Option<usize> {
+        let capacity = self.pool.buf.capacity();
+        let mut offset = self.offset.load(Ordering::Relaxed);
+        loop {
+            // Do not bump past the capacity, a gap without header breaks the iteration.
+            if capacity - offset < size {
+                self.failed_allocation_count.fetch_add(1, Ordering::Relaxed);
+                return None;
+            }
+            match self.offset.compare_exchange_weak(
+                offset,
+                offset + size,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    #[cfg(debug_assertions)]
+                    self.starts.lock().unwrap().push(offset);
+                    return Some(offset);
+                }
+                Err(v) => offset = v,
+            }
+        }
+    }
+
+    /// Allocate an object and return the mutable reference.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: PoolObjectTypeId + Default + Send>(
+        &self,
+        def_val: Option<T>,
+    ) -> Option<&mut T> {
+        let size = std::mem::size_of::<T>();
+        let offset = self.reserve(size)?;
+        unsafe {
+            let ptr = self.ptr.add(offset) as *mut T;
+            std::ptr::write(ptr, def_val.unwrap_or_default());
+            let obj = &mut *ptr;
+            let type_ = obj.pool_object_type_id();
+            let base = &mut *(ptr as *mut PoolObjectBase);
+            base.set_pool_object_type(type_);
+            base.set_pool_object_size(size);
+            Some(obj)
+        }
+    }
+
+    /// Clear all objects in the pool.
+    pub fn clear(&mut self) {
+        self.sync();
+        *self.offset.get_mut() = 0;
+        self.pool.clear();
+    }
+
+    /// Catch up the snapshot with the allocations since the last sync and record their
+    /// statistics.
+    fn sync(&mut self) {
+        let offset = *self.offset.get_mut();
+        let mut cursor = self.pool.offset;
+        self.pool.offset = offset;
+        #[cfg(debug_assertions)]
+        {
+            // The regions are reserved in order, but the offsets may be pushed out of order
+            let starts = self.starts.get_mut().unwrap();
+            starts.sort_unstable();
+            self.pool.starts.append(starts);
+        }
+        while cursor < offset {
+            let Some(size) = self.pool.checked_object_size(cursor) else {
+                break;
+            };
+            let base = unsafe { &*(self.pool.buf.as_ptr().add(cursor) as *const PoolObjectBase) };
+            self.pool.record_alloc(base.pool_object_type(), size);
+            cursor += size;
+        }
+        self.pool.failed_allocation_count += std::mem::take(self.failed_allocation_count.get_mut());
+    }
+
+    /// Returns the frozen snapshot of the pool for reading the objects.
+    pub fn freeze(&mut self) -> &ForwardPool {
+        self.sync();
+        &self.pool
+    }
+
+    /// Returns an iterator that allows modifying each object.
+    pub fn iter_mut(&mut self) -> ForwardPoolIterMut<'_> {
+        self.sync();
+        self.pool.iter_mut()
+    }
+}
+
+/// Handle of an object allocated by [`SlotPool`].
+///
+/// The handle carries the generation of the slot, so accessing a freed object through
+/// the stale handle is detected even if the slot has been reused by another object.
+pub struct PoolHandle<T> {
+    index: u32,
+    generation: u32,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> PoolHandle<T> {
+    /// Returns the index of the slot.
+    pub fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    /// Returns the generation of the slot.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+impl<T> Copy for PoolHandle<T> {}
+
+impl<T> Clone for PoolHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> PartialEq for PoolHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.generation == other.generation
+    }
+}
+
+impl<T> Eq for PoolHandle<T> {}
+
+impl<T> std::hash::Hash for PoolHandle<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<T> std::fmt::Debug for PoolHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PoolHandle")
+            .field("index", &self.index)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+}
+
+/// Slot Reuse Object Pool.
+///
+/// Unlike the [`ForwardPool`], the objects can be freed individually and their slots
+/// are reused by the following allocations.
+///
+/// # Examples
+///
+/// ```
+/// use nuki::object_pool::SlotPool;
+///
+/// let mut pool = SlotPool::<u64>::with_capacity(16);
+/// let a = pool.alloc(Some(1));
+/// let b = pool.alloc(None);
+/// *pool.get_mut(b).unwrap() += 2;
+/// assert_eq!(pool.get(b), Some(&2));
+///
+/// assert_eq!(pool.free(a), Some(1));
+/// assert_eq!(pool.get(a), None);
+///
+/// // The slot of `a` is reused, but the stale handle is still rejected.
+/// let c = pool.alloc(Some(3));
+/// assert_eq!(c.index(), a.index());
+/// assert_eq!(pool.get(a), None);
+/// assert_eq!(pool.iter().map(|(_, v)| *v).sum::<u64>(), 5);
+/// ```
+#[derive(Debug)]
+pub struct SlotPool<T> {
+    slots: Vec<Slot<T>>,
+    free_list: Vec<u32>,
+    len: usize,
+}
+
+impl<T> Default for SlotPool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SlotPool<T> {
+    /// Create an empty pool.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create the pool with capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free_list: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of live objects in the pool.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the pool contains no live objects.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Allocate an object and return the handle.
+    pub fn alloc(&mut self, def_val: Option<T>) -> PoolHandle<T>
+    where
+        T: Default,
+    {
+        self.insert(def_val.unwrap_or_default())
+    }
+
+    /// Insert an object and return the handle.
+    pub fn insert(&mut self, value: T) -> PoolHandle<T> {
+        self.len += 1;
+        if let Some(index) = self.free_list.pop() {
+            let slot = &mut self.slots[index as usize];
+            slot.value = Some(value);
+            PoolHandle {
+                index,
+                generation: slot.generation,
+                _marker: PhantomData,
+            }
+        } else {
+            let index = self.slots.len() as u32;
+            self.slots.push(Slot {
+                generation: 0,
+                value: Some(value),
+            });
+            PoolHandle {
+                index,
+                generation: 0,
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    /// Returns `true` if the handle refers to a live object.
+    pub fn contains(&self, handle: PoolHandle<T>) -> bool {
+        self.get(handle).is_some()
+    }
+
+    /// Returns the reference to the object, or `None` if the handle is stale.
+    pub fn get(&self, handle: PoolHandle<T>) -> Option<&T> {
+        self.slots
+            .get(handle.index as usize)
+            .filter(|s| s.generation == handle.generation)
+            .and_then(|s| s.value.as_ref())
+    }
+
+    /// Returns the mutable reference to the object, or `None` if the handle is stale.
+    pub fn get_mut(&mut self, handle: PoolHandle<T>) -> Option<&mut T> {
+        self.slots
+            .get_mut(handle.index as usize)
+            .filter(|s| s.generation == handle.generation)
+            .and_then(|s| s.value.as_mut())
+    }
+
+    /// Free the object and return it, or `None` if the handle is stale.
+    pub fn free(&mut self, handle: PoolHandle<T>) -> Option<T> {
+        let slot = self
+            .slots
+            .get_mut(handle.index as usize)
+            .filter(|s| s.generation == handle.generation)?;
+        let value = slot.value.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_list.push(handle.index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Free all objects in the pool, all handles become stale.
+    pub fn clear(&mut self) {
+        self.free_list.clear();
+        for (i, slot) in self.slots.iter_mut().enumerate().rev() {
+            if slot.value.take().is_some() {
+                slot.generation = slot.generation.wrapping_add(1);
+            }
+            self.free_list.push(i as u32);
+        }
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the live objects.
+    pub fn iter(&self) -> impl Iterator<Item = (PoolHandle<T>, &T)> {
+        self.slots.iter().enumerate().filter_map(|(i, s)| {
+            s.value.as_ref().map(|v| {
+                let handle = PoolHandle {
+                    index: i as u32,
+                    generation: s.generation,
+                    _marker: PhantomData,
+                };
+                (handle, v)
+            })
+        })
+    }
+
+    /// Returns an iterator that allows modifying each live object.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (PoolHandle<T>, &mut T)> {
+        self.slots.iter_mut().enumerate().filter_map(|(i, s)| {
+            let generation = s.generation;
+            s.value.as_mut().map(|v| {
+                let handle = PoolHandle {
+                    index: i as u32,
+                    generation,
+                    _marker: PhantomData,
+                };
+                (handle, v)
+            })
+        })
+    }
+}
+
+/// Usage statistics of a type of the Pooled Objects.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolTypeStats {
+    /// The number of the objects allocated.
+    pub count: usize,
+    /// The total bytes of the objects allocated.
+    pub bytes: usize,
+}
+
+/// Usage statistics report of the [`ForwardPool`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolReport {
+    /// The capacity of the pool in bytes.
+    pub capacity: usize,
+    /// The used bytes at the time of reporting.
+    pub used: usize,
+    /// The high-water mark of used bytes.
+    pub peak_used: usize,
+    /// The number of succeeded allocations.
+    pub allocation_count: usize,
+    /// The number of failed allocations.
+    pub failed_allocation_count: usize,
+    /// The per-type statistics, empty if not enabled.
+    pub type_stats: BTreeMap<usize, PoolTypeStats>,
+}
+
 /// Pooled Object Iterator.
 #[derive(Debug)]
 pub struct ForwardPoolIter<'pool> {
@@ -128,10 +832,18 @@ impl<'pool> Iterator for ForwardPoolIter<'pool> {
     fn next(&mut self) -> Option<Self::Item> {
         if self.cursor >= self.pool.used() {
             None
+        } else if let Some(size) = self.pool.checked_object_size(self.cursor) {
+            let ptr = unsafe { self.pool.as_ptr().add(self.cursor) };
+            let base = unsafe { &*(ptr as *const PoolObjectBase) };
+            self.cursor += size;
+            if base.pool_object_type() == POOL_OBJECT_TYPE_ARRAY {
+                Some(unsafe { &*(ptr as *const PoolArrayBase) })
+            } else {
+                Some(base)
+            }
         } else {
-            let base = unsafe { &*(self.pool.as_ptr().add(self.cursor) as *const PoolObjectBase) };
-            self.cursor += base.pool_object_size();
-            Some(base)
+            self.cursor = self.pool.used();
+            None
         }
     }
 }
@@ -155,11 +867,18 @@ impl<'pool> Iterator for ForwardPoolIterMut<'pool> {
     fn next(&mut self) -> Option<Self::Item> {
         if self.cursor >= self.pool.used() {
             None
+        } else if let Some(size) = self.pool.checked_object_size(self.cursor) {
+            let ptr = unsafe { self.pool.as_mut_ptr().add(self.cursor) };
+            let base = unsafe { &mut *(ptr as *mut PoolObjectBase) };
+            self.cursor += size;
+            if base.pool_object_type() == POOL_OBJECT_TYPE_ARRAY {
+                Some(unsafe { &mut *(ptr as *mut PoolArrayBase) })
+            } else {
+                Some(base)
+            }
         } else {
-            let base =
-                unsafe { &mut *(self.pool.as_mut_ptr().add(self.cursor) as *mut PoolObjectBase) };
-            self.cursor += base.pool_object_size();
-            Some(base)
+            self.cursor = self.pool.used();
+            None
         }
     }
 }
@@ -178,6 +897,60 @@ pub struct PoolObjectBase {
     size: u16,
 }
 
+/// Base attributes of the Pooled Array.
+///
+/// The header of an array allocated by [`ForwardPool::alloc_slice`], the elements are
+/// stored after the header at `data_offset`.
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct PoolArrayBase {
+    base: PoolObjectBase,
+    elem_type: u16,
+    elem_size: u16,
+    len: u16,
+    data_offset: u16,
+}
+
+impl PoolArrayBase {
+    /// Returns the type of the elements.
+    pub fn elem_type(&self) -> usize {
+        self.elem_type as usize
+    }
+
+    /// Returns the size of each element.
+    pub fn elem_size(&self) -> usize {
+        self.elem_size as usize
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns `true` if the array contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the elements as a slice.
+    /// # Safety
+    /// `T` must be the type of the elements which the array allocated with.
+    pub unsafe fn as_slice<T>(&self) -> &[T] {
+        debug_assert_eq!(std::mem::size_of::<T>(), self.elem_size());
+        let ptr = (self as *const Self as *const u8).add(self.data_offset as usize);
+        std::slice::from_raw_parts(ptr as *const T, self.len())
+    }
+
+    /// Returns the elements as a mutable slice.
+    /// # Safety
+    /// `T` must be the type of the elements which the array allocated with.
+    pub unsafe fn as_mut_slice<T>(&mut self) -> &mut [T] {
+        debug_assert_eq!(std::mem::size_of::<T>(), self.elem_size());
+        let ptr = (self as *mut Self as *mut u8).add(self.data_offset as usize);
+        std::slice::from_raw_parts_mut(ptr as *mut T, self.len())
+    }
+}
+
 /// Pooled Object Abstract.
 pub trait PoolObject {
     /// Returns the reference to the `base`.
@@ -197,6 +970,16 @@ pub trait PoolObject {
 
     /// Set the size of the pooled object.
     fn set_pool_object_size(&mut self, size: usize);
+
+    /// Returns the reference to the array header if the object is a pooled array.
+    fn as_pool_array(&self) -> Option<&PoolArrayBase> {
+        None
+    }
+
+    /// Returns the mutable reference to the array header if the object is a pooled array.
+    fn as_pool_array_mut(&mut self) -> Option<&mut PoolArrayBase> {
+        None
+    }
 }
 
 impl PoolObject for PoolObjectBase {
@@ -225,6 +1008,40 @@ impl PoolObject for PoolObjectBase {
     }
 }
 
+impl PoolObject for PoolArrayBase {
+    fn pool_object_base(&self) -> &PoolObjectBase {
+        &self.base
+    }
+
+    fn pool_object_base_mut(&mut self) -> &mut PoolObjectBase {
+        &mut self.base
+    }
+
+    fn pool_object_type(&self) -> usize {
+        self.base.pool_object_type()
+    }
+
+    fn set_pool_object_type(&mut self, type_: usize) {
+        self.base.set_pool_object_type(type_)
+    }
+
+    fn pool_object_size(&self) -> usize {
+        self.base.pool_object_size()
+    }
+
+    fn set_pool_object_size(&mut self, size: usize) {
+        self.base.set_pool_object_size(size)
+    }
+
+    fn as_pool_array(&self) -> Option<&PoolArrayBase> {
+        Some(self)
+    }
+
+    fn as_pool_array_mut(&mut self) -> Option<&mut PoolArrayBase> {
+        Some(self)
+    }
+}
+
 /// TypeId of the Pooled Object.
 pub trait PoolObjectTypeId {
     /// Returns the TypeId of the target Type.
@@ -251,11 +1068,17 @@ mod tests {
         vals: [u64; 8],
     }
 
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct ObjectTag {
+        val: u32,
+    }
+
     enum ObjectType {
         Unknown,
         Foo,
         Bar,
         FooBar,
+        Tag,
     }
 
     impl From<usize> for ObjectType {
@@ -264,6 +1087,7 @@ mod tests {
                 1 => ObjectType::Foo,
                 2 => ObjectType::Bar,
                 3 => ObjectType::FooBar,
+                4 => ObjectType::Tag,
                 _ => ObjectType::Unknown,
             }
         }
@@ -286,6 +1110,12 @@ mod tests {
         }
     }
 
+    impl PoolObjectTypeId for ObjectTag {
+        fn pool_object_type_id(&self) -> usize {
+            ObjectType::Tag as usize
+        }
+    }
+
     impl PoolObjectTypeId for ObjectBar {
         fn pool_object_type_id(&self) -> usize {
             ObjectType::Bar as usize
@@ -353,4 +1183,392 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_forward_pool_alloc_slice() {
+        let mut pool = ForwardPool::with_capacity(1024);
+
+        assert!(pool.alloc::<ObjectFoo>(None).is_some());
+        let tags = pool
+            .alloc_slice(16, |i| ObjectTag { val: i as u32 })
+            .unwrap();
+        assert_eq!(tags.len(), 16);
+        assert_eq!(tags[15], ObjectTag { val: 15 });
+        assert!(pool.alloc::<ObjectFoo>(None).is_some());
+
+        let objs: Vec<&dyn PoolObject> = pool.iter().collect();
+        assert_eq!(objs.len(), 3);
+        assert_eq!(objs[0].pool_object_type(), ObjectType::Foo as usize);
+        assert!(objs[0].as_pool_array().is_none());
+        assert_eq!(objs[1].pool_object_type(), POOL_OBJECT_TYPE_ARRAY);
+        let array = objs[1].as_pool_array().unwrap();
+        assert_eq!(array.len(), 16);
+        assert_eq!(array.elem_type(), ObjectType::Tag as usize);
+        assert_eq!(array.elem_size(), std::mem::size_of::<ObjectTag>());
+        let tags = unsafe { array.as_slice::<ObjectTag>() };
+        assert!(tags.iter().enumerate().all(|(i, t)| t.val == i as u32));
+        assert_eq!(objs[2].pool_object_type(), ObjectType::Foo as usize);
+
+        for o in pool.iter_mut() {
+            if let Some(array) = o.as_pool_array_mut() {
+                for t in unsafe { array.as_mut_slice::<ObjectTag>() } {
+                    t.val *= 2;
+                }
+            }
+        }
+        let array = pool.iter().nth(1).unwrap().as_pool_array().unwrap();
+        assert_eq!(unsafe { array.as_slice::<ObjectTag>() }[3].val, 6);
+    }
+
+    #[test]
+    fn test_forward_pool_alloc_slice_empty() {
+        let mut pool = ForwardPool::with_capacity(64);
+
+        let tags = pool
+            .alloc_slice(0, |i| ObjectTag { val: i as u32 })
+            .unwrap();
+        assert!(tags.is_empty());
+        assert!(pool.used() >= std::mem::size_of::<PoolArrayBase>());
+        assert_eq!(pool.used() % std::mem::align_of::<u64>(), 0);
+
+        let array = pool.iter().next().unwrap().as_pool_array().unwrap();
+        assert!(array.is_empty());
+        assert_eq!(array.elem_type(), 0);
+        assert_eq!(pool.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_forward_pool_alloc_slice_exact_fill() {
+        let header_size = std::mem::size_of::<PoolArrayBase>();
+        let elem_size = std::mem::size_of::<ObjectTag>();
+        let mut pool = ForwardPool::with_capacity(256);
+        let n = (pool.space() - header_size) / elem_size;
+        assert_eq!(header_size + elem_size * n, pool.space());
+
+        assert!(pool.alloc_slice(n + 1, |_| ObjectTag::default()).is_none());
+        assert_eq!(pool.used(), 0);
+        assert_eq!(
+            pool.alloc_slice(n, |_| ObjectTag::default()).unwrap().len(),
+            n
+        );
+        assert_eq!(pool.space(), 0);
+        assert!(pool.alloc_slice(0, |_| ObjectTag::default()).is_none());
+        assert_eq!(pool.iter().count(), 1);
+    }
+
+    impl PoolObjectTypeId for u64 {}
+
+    #[test]
+    fn test_forward_pool_alloc_slice_too_large() {
+        let mut pool = ForwardPool::with_capacity(128 * 1024);
+
+        assert!(pool.alloc_slice(u16::MAX as usize, |_| 0u64).is_none());
+        assert_eq!(pool.used(), 0);
+    }
+
+    impl PoolObjectTypeId for () {}
+
+    impl PoolObjectTypeId for [u8; 65536] {}
+
+    #[test]
+    fn test_forward_pool_alloc_slice_unrepresentable() {
+        let mut pool = ForwardPool::with_capacity(1024);
+
+        assert!(pool.alloc_slice(4, |_| ()).is_none());
+        assert!(pool.alloc_slice(0, |_| ()).is_none());
+        assert!(pool.alloc_slice(0, |_| [0u8; 65536]).is_none());
+        assert_eq!(pool.used(), 0);
+        assert_eq!(pool.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_forward_pool_stats() {
+        let mut pool = ForwardPool::with_capacity(1024);
+        pool.set_type_stats_enabled(true);
+
+        let mut frames = vec![];
+        for n in [3, 8, 1] {
+            pool.clear();
+            for _ in 0..n {
+                pool.alloc::<ObjectFoo>(None).unwrap();
+            }
+            pool.alloc_slice(4, |i| ObjectTag { val: i as u32 })
+                .unwrap();
+            frames.push(pool.used());
+        }
+        while pool.alloc::<ObjectBar>(None).is_some() {}
+
+        let report = pool.report();
+        assert_eq!(report.capacity, 1024);
+        assert_eq!(report.used, pool.used());
+        assert_eq!(pool.peak_used(), frames[1].max(pool.used()));
+        assert_eq!(report.peak_used, pool.peak_used());
+        assert_eq!(pool.failed_allocation_count(), 1);
+        let bars = (1024 - frames[2]) / std::mem::size_of::<ObjectBar>();
+        assert_eq!(pool.allocation_count(), 3 + 8 + 1 + 3 + bars);
+        let foo = report.type_stats[&(ObjectType::Foo as usize)];
+        assert_eq!(foo.count, 12);
+        assert_eq!(foo.bytes, 12 * std::mem::size_of::<ObjectFoo>());
+        assert_eq!(report.type_stats[&POOL_OBJECT_TYPE_ARRAY].count, 3);
+        assert_eq!(report.type_stats[&(ObjectType::Bar as usize)].count, bars);
+
+        pool.reset_stats();
+        assert_eq!(pool.peak_used(), pool.used());
+        assert_eq!(pool.allocation_count(), 0);
+        assert_eq!(pool.failed_allocation_count(), 0);
+        assert!(pool.report().type_stats.is_empty());
+
+        pool.set_type_stats_enabled(false);
+        pool.clear();
+        pool.alloc::<ObjectFoo>(None).unwrap();
+        assert_eq!(pool.allocation_count(), 1);
+        assert!(pool.report().type_stats.is_empty());
+    }
+
+    #[test]
+    fn test_sync_forward_pool() {
+        const THREADS: usize = 8;
+        let mut pool = SyncForwardPool::with_capacity(64 * 1024);
+        pool.set_type_stats_enabled(true);
+
+        let counts: Vec<usize> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|i| {
+                    let pool = &pool;
+                    s.spawn(move || {
+                        let mut n = 0;
+                        loop {
+                            let ok = if n % 2 == 0 {
+                                pool.alloc(Some(ObjectFoo {
+                                    val: i as u64,
+                                    ..Default::default()
+                                }))
+                                .is_some()
+                            } else {
+                                pool.alloc(Some(ObjectBar {
+                                    vals: [i as u64; 8],
+                                    ..Default::default()
+                                }))
+                                .is_some()
+                            };
+                            if !ok {
+                                break n;
+                            }
+                            n += 1;
+                        }
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let total: usize = counts.iter().sum();
+        let used = pool.used();
+        let snapshot = pool.freeze();
+        assert_eq!(snapshot.used(), used);
+        let mut seen = vec![0usize; THREADS];
+        for o in snapshot.iter() {
+            match ObjectType::from(o.pool_object_type()) {
+                ObjectType::Foo => {
+                    assert_eq!(o.pool_object_size(), std::mem::size_of::<ObjectFoo>());
+                    let t = unsafe { &*(o.pool_object_base() as *const _ as *const ObjectFoo) };
+                    seen[t.val as usize] += 1;
+                }
+                ObjectType::Bar => {
+                    assert_eq!(o.pool_object_size(), std::mem::size_of::<ObjectBar>());
+                    let t = unsafe { &*(o.pool_object_base() as *const _ as *const ObjectBar) };
+                    assert!(t.vals.iter().all(|v| *v == t.vals[0]));
+                    seen[t.vals[0] as usize] += 1;
+                }
+                _ => panic!("corrupted object header"),
+            }
+        }
+        assert_eq!(seen, counts);
+        assert_eq!(seen.iter().sum::<usize>(), total);
+
+        let report = pool.freeze().report();
+        assert_eq!(report.allocation_count, total);
+        assert_eq!(report.failed_allocation_count, THREADS);
+        assert_eq!(report.peak_used, used);
+        let foo = report.type_stats[&(ObjectType::Foo as usize)];
+        let bar = report.type_stats[&(ObjectType::Bar as usize)];
+        assert_eq!(foo.count + bar.count, total);
+        assert_eq!(foo.bytes + bar.bytes, used);
+
+        pool.clear();
+        assert_eq!(pool.used(), 0);
+        assert_eq!(pool.freeze().iter().count(), 0);
+        assert_eq!(pool.freeze().allocation_count(), total);
+    }
+
+    fn corrupted_pool() -> ForwardPool {
+        let mut pool = ForwardPool::with_capacity(1024);
+        pool.set_strict(false);
+        for _ in 0..4 {
+            pool.alloc::<ObjectFoo>(None).unwrap();
+        }
+        pool
+    }
+
+    fn poke_header(pool: &mut ForwardPool, index: usize) -> &mut PoolObjectBase {
+        let offset = index * std::mem::size_of::<ObjectFoo>();
+        unsafe { &mut *(pool.as_mut_ptr().add(offset) as *mut PoolObjectBase) }
+    }
+
+    #[test]
+    fn test_forward_pool_corrupted_zero_size() {
+        let mut pool = corrupted_pool();
+        poke_header(&mut pool, 1).set_pool_object_size(0);
+
+        let mut iter = pool.iter();
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+        assert!(pool.is_corrupted());
+
+        pool.clear();
+        assert!(!pool.is_corrupted());
+    }
+
+    #[test]
+    fn test_forward_pool_corrupted_oversize() {
+        let mut pool = corrupted_pool();
+        poke_header(&mut pool, 3).set_pool_object_size(u16::MAX as usize - 1);
+
+        assert_eq!(pool.iter_mut().count(), 3);
+        assert!(pool.is_corrupted());
+    }
+
+    #[test]
+    #[should_panic(expected = "corrupted object header")]
+    fn test_forward_pool_corrupted_strict() {
+        let mut pool = corrupted_pool();
+        pool.set_strict(true);
+        let size = std::mem::size_of::<ObjectFoo>();
+        poke_header(&mut pool, 2).set_pool_object_size(size - 1);
+
+        pool.iter().count();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_forward_pool_corrupted_unallocated() {
+        // The `val` of the third object forges a header right behind the second one.
+        let size = std::mem::size_of::<ObjectFoo>();
+        let mut forged = [0u8; 8];
+        forged[..2].copy_from_slice(&(ObjectType::Foo as u16).to_ne_bytes());
+        forged[2..4].copy_from_slice(&8u16.to_ne_bytes());
+        let mut pool = ForwardPool::with_capacity(1024);
+        pool.set_strict(false);
+        for i in 0..4 {
+            let val = if i == 2 {
+                u64::from_ne_bytes(forged)
+            } else {
+                0
+            };
+            pool.alloc(Some(ObjectFoo {
+                val,
+                ..Default::default()
+            }))
+            .unwrap();
+        }
+        poke_header(&mut pool, 1).set_pool_object_size(size + 8);
+
+        assert_eq!(pool.iter().count(), 2);
+        assert!(pool.is_corrupted());
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Default)]
+    struct ObjectFake {
+        base: PoolObjectBase,
+    }
+
+    impl PoolObjectTypeId for ObjectFake {
+        fn pool_object_type_id(&self) -> usize {
+            POOL_OBJECT_TYPE_ARRAY
+        }
+    }
+
+    #[test]
+    fn test_forward_pool_corrupted_array() {
+        let mut pool = corrupted_pool();
+        assert!(pool.alloc::<ObjectFake>(None).is_none());
+        assert_eq!(pool.failed_allocation_count(), 1);
+        assert_eq!(pool.iter().count(), 4);
+
+        // A plain object header claims to be an array, the `data_offset` overlaps the zero `val`.
+        poke_header(&mut pool, 2).set_pool_object_type(POOL_OBJECT_TYPE_ARRAY);
+        assert_eq!(pool.iter().count(), 2);
+        assert!(pool.is_corrupted());
+    }
+
+    #[test]
+    fn test_forward_pool_intact() {
+        let mut pool = corrupted_pool();
+        pool.alloc_slice(3, |i| ObjectTag { val: i as u32 })
+            .unwrap();
+
+        assert_eq!(pool.iter().count(), 5);
+        assert_eq!(pool.iter_mut().count(), 5);
+        assert!(!pool.is_corrupted());
+    }
+
+    #[test]
+    fn test_slot_pool() {
+        let mut pool = SlotPool::<ObjectTag>::with_capacity(4);
+        let handles: Vec<_> = (0..8)
+            .map(|i| pool.alloc(Some(ObjectTag { val: i })))
+            .collect();
+        assert_eq!(pool.len(), 8);
+        assert_eq!(pool.get(handles[3]), Some(&ObjectTag { val: 3 }));
+
+        pool.get_mut(handles[5]).unwrap().val = 50;
+        assert_eq!(pool.free(handles[2]), Some(ObjectTag { val: 2 }));
+        assert_eq!(pool.free(handles[6]), Some(ObjectTag { val: 6 }));
+        assert_eq!(pool.len(), 6);
+
+        let vals: Vec<u32> = pool.iter().map(|(_, v)| v.val).collect();
+        assert_eq!(vals, vec![0, 1, 3, 4, 50, 7]);
+        for (h, v) in pool.iter_mut() {
+            v.val = h.index() as u32 * 10;
+        }
+        assert_eq!(pool.get(handles[7]), Some(&ObjectTag { val: 70 }));
+
+        // The freed slots are reused before growing.
+        let h = pool.alloc(None);
+        assert!(h.index() == 2 || h.index() == 6);
+        assert_eq!(pool.get(h), Some(&ObjectTag::default()));
+        pool.alloc(None);
+        assert_eq!(pool.alloc(None).index(), 8);
+        assert_eq!(pool.len(), 9);
+    }
+
+    #[test]
+    fn test_slot_pool_stale_handle() {
+        let mut pool = SlotPool::<ObjectTag>::new();
+        let a = pool.alloc(Some(ObjectTag { val: 1 }));
+
+        assert!(pool.free(a).is_some());
+        assert!(pool.free(a).is_none());
+        assert!(!pool.contains(a));
+
+        // ABA: the slot is reused by another object, the old handle must not see it.
+        let b = pool.alloc(Some(ObjectTag { val: 2 }));
+        assert_eq!(a.index(), b.index());
+        assert_ne!(a.generation(), b.generation());
+        assert_ne!(a, b);
+        assert!(pool.get(a).is_none());
+        assert!(pool.get_mut(a).is_none());
+        assert!(pool.free(a).is_none());
+        assert_eq!(pool.get(b), Some(&ObjectTag { val: 2 }));
+
+        pool.clear();
+        assert!(pool.is_empty());
+        assert!(pool.get(b).is_none());
+        let c = pool.alloc(None);
+        assert_eq!(c.index(), b.index());
+        assert!(pool.get(b).is_none());
+        assert!(pool.get(c).is_some());
+    }
 }